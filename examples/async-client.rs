@@ -1,15 +1,13 @@
 use atoi::comm::http::DummyHttpClient;
 use atoi::types::Node;
 use atoi::AsyncClient;
+use std::sync::Arc;
 
 #[async_std::main]
 async fn main() {
     let client = AsyncClient::builder()
-        .node(Node {
-            url: String::from("https://example.iota.org"),
-            auth: None,
-        })
-        .http(Box::new(DummyHttpClient::new()))
+        .nodes(vec!["https://example.iota.org".parse::<Node>().unwrap()])
+        .http(Arc::new(DummyHttpClient::new()))
         .build();
 
     let health = client.health().await;
@@ -1,14 +1,12 @@
 use atoi::comm::http::CurlHttpClient;
-use atoi::types::Node;
+use atoi::types::Network;
 use atoi::Client;
+use std::sync::Arc;
 
 fn main() {
     let client = Client::builder()
-        .node(Node {
-            url: String::from("https://api.alphanet.iotaledger.net"),
-            auth: None,
-        })
-        .http(Box::new(CurlHttpClient::new()))
+        .nodes(Network::alphanet())
+        .http(Arc::new(CurlHttpClient::new()))
         .build();
 
     let health = client.health();
@@ -5,7 +5,7 @@
 extern crate alloc;
 extern crate wee_alloc;
 
-use alloc::{boxed::Box, string::String};
+use alloc::{sync::Arc, vec};
 use atoi::comm::http::DummyHttpClient;
 use atoi::types::Node;
 use atoi::Client;
@@ -35,11 +35,8 @@ pub extern "C" fn _start() -> ! {
 
 fn main() {
     let client = Client::builder()
-        .node(Node {
-            url: String::from("https://example.iota.org"),
-            auth: None,
-        })
-        .http(Box::new(DummyHttpClient::new()))
+        .nodes(vec!["https://example.iota.org".parse::<Node>().unwrap()])
+        .http(Arc::new(DummyHttpClient::new()))
         .build();
 
     let health = client.health();
@@ -0,0 +1,807 @@
+//! The state machine of a single API call over a set of nodes.
+
+#[cfg(feature = "std")]
+use super::RateLimiter;
+use super::{Endpoint, MediaType, Method, Request, Response};
+use crate::cache::{Cache, CachePolicy};
+#[cfg(feature = "metrics")]
+use crate::metrics::Recorder;
+use crate::types::{Auth, Error, Node, Quorum, Result};
+use alloc::{string::String, vec::Vec};
+use core::time::Duration;
+
+/// An HTTP request to be performed by the driver of a [`Call`].
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Transmit<'a> {
+    pub method: Method,
+    pub url: alloc::string::String,
+    pub auth: Option<&'a Auth>,
+    pub body: Vec<u8>,
+    /// The media type of `body`, to be sent as `Content-Type`.
+    pub content_type: MediaType,
+    /// The media type of the response, to be sent as `Accept`.
+    pub accept: MediaType,
+    /// The node the request is sent to, as an index into the nodes given to [`Call::new`].
+    pub node: usize,
+    /// How long to wait before sending the request, to respect rate limits.
+    pub delay: Duration,
+}
+
+/// What the driver of a [`Call`] should do next.
+#[derive(Debug)]
+pub enum Step<'a, T> {
+    /// Perform an HTTP request, and report its result with [`Call::receive`].
+    Transmit(Transmit<'a>),
+    /// The call has finished. It must not be stepped again.
+    Done(Result<T>),
+}
+
+/// The state of an API call.
+///
+/// Without quorum, the request is sent to the first node, and on failure retried on the next
+/// nodes in turn, at most `retries` times. Only failures of the transport, server errors (`5xx`)
+/// and rate limiting (`429`) are retried, and only for [idempotent](Endpoint::idempotent)
+/// requests; other errors, like a block rejected by the node, are final. With quorum (if both
+/// configured and the [`Endpoint`] asks for it), the request is sent to [`Quorum::size`] distinct
+/// nodes, and succeeds once [`Quorum::threshold`] of them returned responses that agree, i.e.
+/// with identical [quorum keys](Endpoint::quorum_key).
+///
+/// With a [`Cache`], cacheable `GET` requests are answered from it without any request, and
+/// successful responses are stored into it.
+///
+/// With the `tracing` feature, a span is opened for the call, and another one for every request
+/// sent, which drivers enter while performing the request (see [`Call::attempt_span`]); with the `metrics` feature, a [`Recorder`](crate::metrics::Recorder) is informed about
+/// requests.
+pub struct Call<'a, E: Endpoint> {
+    endpoint: &'a E,
+    request: Request,
+    nodes: &'a [Node],
+    /// Indices of the nodes requests may be sent to, in order.
+    candidates: Vec<usize>,
+    retries: usize,
+    quorum: Option<Quorum>,
+    cache: Option<&'a dyn Cache>,
+    #[cfg(feature = "std")]
+    rate_limiter: Option<&'a RateLimiter>,
+    #[cfg(feature = "metrics")]
+    metrics: Option<&'a dyn Recorder>,
+    #[cfg(feature = "tracing")]
+    span: tracing::Span,
+    /// The span of the request in flight.
+    #[cfg(feature = "tracing")]
+    attempt_span: tracing::Span,
+    #[cfg(feature = "tracing")]
+    started_at: std::time::Instant,
+    #[cfg(feature = "tracing")]
+    sent_at: std::time::Instant,
+    /// Index of the node the first request is sent to.
+    first: usize,
+    /// Number of requests handed out so far.
+    sent: usize,
+    /// Index of the node the last request was sent to.
+    current: usize,
+    /// The result of the last request, not yet processed.
+    received: Option<Result<Response>>,
+    /// Distinct quorum keys of successful responses, with the first response of each and their
+    /// number of occurrences (quorum only).
+    tally: Vec<(Vec<u8>, Vec<u8>, usize)>,
+}
+
+impl<'a, E: Endpoint> Call<'a, E> {
+    /// Start a call to `endpoint` over `nodes`.
+    pub fn new(
+        endpoint: &'a E,
+        nodes: &'a [Node],
+        retries: usize,
+        quorum: Option<Quorum>,
+    ) -> Result<Self> {
+        if nodes.is_empty() {
+            return Err(Error::MissingNode);
+        }
+
+        let quorum = quorum
+            .filter(|quorum| endpoint.quorum() && quorum.size > 1)
+            .map(|quorum| Quorum {
+                size: quorum.size.min(nodes.len()),
+                threshold: quorum.threshold.max(1),
+            });
+
+        Ok(Self {
+            endpoint,
+            request: endpoint.request()?,
+            nodes,
+            candidates: (0..nodes.len()).collect(),
+            retries,
+            quorum,
+            cache: None,
+            #[cfg(feature = "std")]
+            rate_limiter: None,
+            #[cfg(feature = "metrics")]
+            metrics: None,
+            #[cfg(feature = "tracing")]
+            span: tracing::debug_span!(
+                "call",
+                endpoint = endpoint.name(),
+                latency_ms = tracing::field::Empty,
+            ),
+            #[cfg(feature = "tracing")]
+            attempt_span: tracing::Span::none(),
+            #[cfg(feature = "tracing")]
+            started_at: std::time::Instant::now(),
+            #[cfg(feature = "tracing")]
+            sent_at: std::time::Instant::now(),
+            first: 0,
+            sent: 0,
+            current: 0,
+            received: None,
+            tally: Vec::new(),
+        })
+    }
+
+    /// Start with the node at `index` (modulo the number of nodes) instead of the first one, e.g.
+    /// to spread many calls over all nodes.
+    pub fn starting_at(mut self, index: usize) -> Self {
+        self.first = index % self.candidates.len();
+        self
+    }
+
+    /// Only send requests to the nodes at `indices`, in this order, e.g. to the nodes offering the
+    /// [capability](Endpoint::capability) of the endpoint. Empty `indices` are ignored.
+    pub fn restricted_to(mut self, indices: Vec<usize>) -> Self {
+        let indices: Vec<usize> = indices
+            .into_iter()
+            .filter(|&index| index < self.nodes.len())
+            .collect();
+
+        if !indices.is_empty() {
+            self.first %= indices.len();
+            self.quorum = self.quorum.map(|quorum| Quorum {
+                size: quorum.size.min(indices.len()),
+                ..quorum
+            });
+            self.candidates = indices;
+        }
+        self
+    }
+
+    /// The number of distinct nodes the call sends requests to at most: one per attempt, or the
+    /// size of the quorum.
+    #[cfg(any(feature = "sync", feature = "async"))]
+    pub(crate) fn nodes_needed(&self) -> usize {
+        match self.quorum {
+            Some(quorum) => quorum.size,
+            None => self.retries.saturating_add(1),
+        }
+        .min(self.nodes.len())
+    }
+
+    /// Use `cache` to look up and store responses.
+    pub fn with_cache(mut self, cache: Option<&'a dyn Cache>) -> Self {
+        self.cache = cache;
+        self
+    }
+
+    /// Limit the rate of requests to each node with `rate_limiter`.
+    #[cfg(feature = "std")]
+    pub fn with_rate_limiter(mut self, rate_limiter: Option<&'a RateLimiter>) -> Self {
+        self.rate_limiter = rate_limiter;
+        self
+    }
+
+    /// Report requests to `metrics`.
+    #[cfg(feature = "metrics")]
+    pub fn with_metrics(mut self, metrics: Option<&'a dyn Recorder>) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
+    /// Advance the call.
+    pub fn step(&mut self) -> Step<'a, E::Output> {
+        match (self.received.take(), self.quorum) {
+            // Nothing sent yet.
+            (None, _) if self.sent == 0 => match self.cached() {
+                Some(body) => {
+                    #[cfg(feature = "tracing")]
+                    tracing::debug!(parent: &self.span, "served from cache");
+
+                    self.done(self.endpoint.decode(&body))
+                }
+                None => self.transmit(),
+            },
+            (None, _) => self.done(Err(Error::HttpClientError(
+                "no response received for the last request".into(),
+            ))),
+            (Some(Ok(response)), None) => {
+                self.store(&response.body);
+                self.done(self.endpoint.decode_response(&response))
+            }
+            (Some(Err(err)), None) if self.sent <= self.retries && self.may_retry(&err) => {
+                self.transmit()
+            }
+            (Some(Err(err)), None) => self.done(Err(err)),
+            (Some(received), Some(quorum)) => {
+                if let Ok(Response { body, .. }) = received {
+                    let key = self.endpoint.quorum_key(&body);
+
+                    match self.tally.iter_mut().find(|(seen, _, _)| *seen == key) {
+                        Some((_, _, count)) => *count += 1,
+                        None => self.tally.push((key, body, 1)),
+                    }
+                }
+
+                if let Some((_, body, _)) = self
+                    .tally
+                    .iter()
+                    .find(|(_, _, count)| *count >= quorum.threshold)
+                {
+                    let result = self.endpoint.decode(body);
+                    self.store(body);
+                    self.done(result)
+                } else if self.sent < quorum.size {
+                    self.transmit()
+                } else {
+                    self.done(Err(Error::QuorumNotReached))
+                }
+            }
+        }
+    }
+
+    /// The span of the request handed out by the last [`Step::Transmit`], within the span of the
+    /// call. Drivers enter it while performing the request, so that the events of the HTTP client
+    /// belong to the request and the call.
+    #[cfg(feature = "tracing")]
+    pub fn attempt_span(&self) -> &tracing::Span {
+        &self.attempt_span
+    }
+
+    /// Report the result of the request handed out by the last [`Step::Transmit`].
+    pub fn receive(&mut self, response: Result<Response>) {
+        #[cfg(feature = "std")]
+        if let (Some(rate_limiter), Err(Error::RateLimited { retry_after, .. })) =
+            (self.rate_limiter, &response)
+        {
+            let pause = retry_after.map_or(super::rate_limit::DEFAULT_PAUSE, Duration::from_secs);
+            rate_limiter.pause(self.current, pause);
+        }
+
+        #[cfg(feature = "tracing")]
+        {
+            let latency = self.sent_at.elapsed();
+
+            self.attempt_span
+                .record("latency_ms", latency.as_millis() as u64);
+            match &response {
+                Ok(response) => tracing::debug!(
+                    parent: &self.attempt_span,
+                    bytes = response.body.len(),
+                    "response received"
+                ),
+                Err(err) => {
+                    tracing::warn!(parent: &self.attempt_span, error = ?err, "request failed")
+                }
+            }
+            self.attempt_span = tracing::Span::none();
+        }
+
+        #[cfg(feature = "metrics")]
+        if let Some(metrics) = self.metrics {
+            let node = self.nodes[self.current].url.as_str();
+
+            match &response {
+                Ok(response) => {
+                    metrics.bytes_received(self.endpoint.name(), node, response.body.len())
+                }
+                Err(err) => metrics.error(self.endpoint.name(), node, err),
+            }
+        }
+
+        self.received = Some(response);
+    }
+
+    /// Whether the request may be sent again after failing with `err`.
+    fn may_retry(&self, err: &Error) -> bool {
+        err.is_transient() && (self.request.method != Method::Post || self.endpoint.idempotent())
+    }
+
+    fn done(&mut self, result: Result<E::Output>) -> Step<'a, E::Output> {
+        #[cfg(feature = "tracing")]
+        {
+            let latency = self.started_at.elapsed();
+
+            self.span.record("latency_ms", latency.as_millis() as u64);
+            if let Err(err) = &result {
+                tracing::warn!(parent: &self.span, error = ?err, "call failed");
+            }
+        }
+
+        Step::Done(result)
+    }
+
+    /// The cache to use with the policy of the endpoint, if the request is cacheable at all.
+    fn cache(&self) -> Option<(&'a dyn Cache, CachePolicy)> {
+        match (
+            self.cache,
+            self.request.method,
+            self.endpoint.cache_policy(),
+        ) {
+            (_, _, CachePolicy::Never) | (None, _, _) => None,
+            (Some(cache), Method::Get, policy) => Some((cache, policy)),
+            (Some(_), _, _) => None,
+        }
+    }
+
+    /// The key of the response in the cache: the request alone for immutable responses, which
+    /// are the same on every node, or the request to the node at `index` for others.
+    fn cache_key(&self, index: usize, policy: CachePolicy) -> String {
+        match policy {
+            CachePolicy::Immutable => self.request.cache_key(),
+            _ => alloc::format!("{} {}", self.nodes[index].url, self.request.cache_key()),
+        }
+    }
+
+    /// The cached response of the node the first request would be sent to.
+    fn cached(&self) -> Option<Vec<u8>> {
+        self.cache().and_then(|(cache, policy)| {
+            cache.get(&self.cache_key(self.candidates[self.first], policy))
+        })
+    }
+
+    /// Cache the response of the node the last request was sent to.
+    fn store(&self, body: &[u8]) {
+        if let Some((cache, policy)) = self.cache() {
+            let ttl = match policy {
+                CachePolicy::Ttl(ttl) => Some(ttl),
+                _ => None,
+            };
+
+            cache.insert(self.cache_key(self.current, policy), body.to_vec(), ttl);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn delay(&self, node: usize) -> Duration {
+        self.rate_limiter
+            .map_or(Duration::ZERO, |rate_limiter| rate_limiter.acquire(node))
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn delay(&self, _node: usize) -> Duration {
+        Duration::ZERO
+    }
+
+    fn transmit(&mut self) -> Step<'a, E::Output> {
+        let index = self.candidates[(self.first + self.sent) % self.candidates.len()];
+        let node = &self.nodes[index];
+
+        #[cfg(feature = "tracing")]
+        {
+            self.attempt_span = tracing::debug_span!(
+                parent: &self.span,
+                "request",
+                node = %node.url,
+                attempt = self.sent + 1,
+                latency_ms = tracing::field::Empty,
+            );
+            self.sent_at = std::time::Instant::now();
+        }
+
+        #[cfg(feature = "metrics")]
+        if let Some(metrics) = self.metrics {
+            if self.sent > 0 && self.quorum.is_none() {
+                metrics.retry(self.endpoint.name(), node.url.as_str());
+            }
+            metrics.request(self.endpoint.name(), node.url.as_str());
+            if !self.request.body.is_empty() {
+                metrics.bytes_sent(
+                    self.endpoint.name(),
+                    node.url.as_str(),
+                    self.request.body.len(),
+                );
+            }
+        }
+
+        self.sent += 1;
+        self.current = index;
+
+        Step::Transmit(Transmit {
+            method: self.request.method,
+            url: node.url.join(&self.request.path),
+            auth: node.auth.as_ref(),
+            body: self.request.body.clone(),
+            content_type: self.request.content_type,
+            accept: self.request.accept,
+            node: index,
+            delay: self.delay(index),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::NodeErrorKind;
+    use alloc::vec;
+
+    /// A `GET` endpoint returning the response body as is.
+    struct Get;
+
+    impl Endpoint for Get {
+        type Output = Vec<u8>;
+
+        fn request(&self) -> Result<Request> {
+            Ok(Request::get("/get"))
+        }
+
+        fn decode(&self, body: &[u8]) -> Result<Vec<u8>> {
+            Ok(body.to_vec())
+        }
+
+        fn quorum(&self) -> bool {
+            true
+        }
+    }
+
+    /// A `POST` endpoint, optionally idempotent.
+    struct Post {
+        idempotent: bool,
+    }
+
+    impl Endpoint for Post {
+        type Output = Vec<u8>;
+
+        fn request(&self) -> Result<Request> {
+            Ok(Request::post("/post", b"{}".to_vec()))
+        }
+
+        fn decode(&self, body: &[u8]) -> Result<Vec<u8>> {
+            Ok(body.to_vec())
+        }
+
+        fn idempotent(&self) -> bool {
+            self.idempotent
+        }
+    }
+
+    /// A `GET` endpoint cached with the given policy.
+    struct Cached(CachePolicy);
+
+    impl Endpoint for Cached {
+        type Output = Vec<u8>;
+
+        fn request(&self) -> Result<Request> {
+            Ok(Request::get("/cached"))
+        }
+
+        fn decode(&self, body: &[u8]) -> Result<Vec<u8>> {
+            Ok(body.to_vec())
+        }
+
+        fn cache_policy(&self) -> CachePolicy {
+            self.0
+        }
+    }
+
+    fn nodes(count: usize) -> Vec<Node> {
+        (0..count)
+            .map(|index| {
+                alloc::format!("https://node{}.example.iota.org", index)
+                    .parse()
+                    .unwrap()
+            })
+            .collect()
+    }
+
+    fn transport_error() -> Error {
+        Error::HttpClientError("connection refused".into())
+    }
+
+    fn node_error(code: u16) -> Error {
+        Error::from_response(
+            "https://node.example.iota.org",
+            code,
+            br#"{"error":{"code":"0","message":"failed"}}"#,
+        )
+    }
+
+    /// Drive `call`, answering its requests with `responses` in order. Returns the result and the
+    /// nodes requests were sent to.
+    fn drive<E: Endpoint>(
+        mut call: Call<'_, E>,
+        responses: Vec<Result<Vec<u8>>>,
+    ) -> (Result<E::Output>, Vec<usize>) {
+        let mut responses = responses.into_iter();
+        let mut sent = Vec::new();
+
+        loop {
+            match call.step() {
+                Step::Transmit(transmit) => {
+                    sent.push(transmit.node);
+                    let response = responses.next().expect("unexpected request");
+                    call.receive(response.map(Response::from));
+                }
+                Step::Done(result) => return (result, sent),
+            }
+        }
+    }
+
+    #[test]
+    fn retries_transient_errors_on_next_nodes() {
+        let nodes = nodes(4);
+        let call = Call::new(&Get, &nodes, 3, None).unwrap();
+        let responses = vec![
+            Err(transport_error()),
+            Err(node_error(503)),
+            Err(Error::RateLimited {
+                url: "https://node2.example.iota.org".into(),
+                retry_after: None,
+            }),
+            Ok(b"ok".to_vec()),
+        ];
+
+        assert_eq!(
+            drive(call, responses),
+            (Ok(b"ok".to_vec()), vec![0, 1, 2, 3])
+        );
+    }
+
+    #[test]
+    fn gives_up_after_retries() {
+        let nodes = nodes(3);
+        let call = Call::new(&Get, &nodes, 1, None).unwrap();
+        let responses = vec![Err(transport_error()), Err(transport_error())];
+
+        assert_eq!(drive(call, responses), (Err(transport_error()), vec![0, 1]));
+    }
+
+    #[test]
+    fn does_not_retry_client_errors() {
+        let nodes = nodes(3);
+
+        for code in [400, 403, 404] {
+            let call = Call::new(&Get, &nodes, 2, None).unwrap();
+            let (result, sent) = drive(call, vec![Err(node_error(code))]);
+
+            assert_eq!(
+                result.unwrap_err().node_error_kind(),
+                Some(NodeErrorKind::from_code(code))
+            );
+            assert_eq!(sent, vec![0]);
+        }
+    }
+
+    #[test]
+    fn does_not_replay_posts_unless_idempotent() {
+        let nodes = nodes(2);
+
+        let post = Post { idempotent: false };
+        let call = Call::new(&post, &nodes, 1, None).unwrap();
+        assert_eq!(
+            drive(call, vec![Err(transport_error())]),
+            (Err(transport_error()), vec![0])
+        );
+
+        let post = Post { idempotent: true };
+        let call = Call::new(&post, &nodes, 1, None).unwrap();
+        assert_eq!(
+            drive(call, vec![Err(transport_error()), Ok(b"ok".to_vec())]),
+            (Ok(b"ok".to_vec()), vec![0, 1])
+        );
+    }
+
+    #[test]
+    fn sends_only_to_restricted_nodes() {
+        let nodes = nodes(4);
+        let call = Call::new(&Get, &nodes, 1, None)
+            .unwrap()
+            .restricted_to(vec![3, 1, 9])
+            .starting_at(1);
+        let responses = vec![Err(transport_error()), Err(transport_error())];
+
+        assert_eq!(drive(call, responses).1, vec![1, 3]);
+    }
+
+    #[test]
+    fn reaches_quorum_on_identical_responses() {
+        let nodes = nodes(3);
+        let quorum = Some(Quorum {
+            size: 3,
+            threshold: 2,
+        });
+
+        let call = Call::new(&Get, &nodes, 0, quorum).unwrap();
+        let responses = vec![Ok(b"a".to_vec()), Err(transport_error()), Ok(b"a".to_vec())];
+        assert_eq!(drive(call, responses), (Ok(b"a".to_vec()), vec![0, 1, 2]));
+
+        let call = Call::new(&Get, &nodes, 0, quorum).unwrap();
+        let responses = vec![Ok(b"a".to_vec()), Ok(b"b".to_vec()), Ok(b"c".to_vec())];
+        assert_eq!(drive(call, responses).0, Err(Error::QuorumNotReached));
+    }
+
+    #[test]
+    fn reaches_quorum_on_responses_differing_in_node_local_fields() {
+        let nodes = nodes(3);
+        let quorum = Some(Quorum {
+            size: 3,
+            threshold: 2,
+        });
+        let output = crate::api::core::GetOutputMetadata {
+            output_id: "0x9a1a0b1f81a4c25d9d6d0e8c4e6a2d8d4e0f5c2b1a3e7d6c5b4a39281706f5e40100"
+                .parse()
+                .unwrap(),
+        };
+        let metadata = |is_spent: bool, ledger_index: u32| {
+            Ok(alloc::format!(
+                r#"{{
+                    "blockId": "0x9a1a0b1f81a4c25d9d6d0e8c4e6a2d8d4e0f5c2b1a3e7d6c5b4a39281706f5e4",
+                    "transactionId": "0x9a1a0b1f81a4c25d9d6d0e8c4e6a2d8d4e0f5c2b1a3e7d6c5b4a39281706f5e4",
+                    "outputIndex": 1,
+                    "isSpent": {},
+                    "milestoneIndexBooked": 470,
+                    "milestoneTimestampBooked": 1617802102,
+                    "ledgerIndex": {}
+                }}"#,
+                is_spent, ledger_index
+            )
+            .into_bytes())
+        };
+
+        let call = Call::new(&output, &nodes, 0, quorum).unwrap();
+        let responses = vec![metadata(false, 480), metadata(false, 481)];
+        let (result, sent) = drive(call, responses);
+        assert_eq!(result.map(|metadata| metadata.ledger_index), Ok(480));
+        assert_eq!(sent, vec![0, 1]);
+
+        let call = Call::new(&output, &nodes, 0, quorum).unwrap();
+        let responses = vec![
+            metadata(false, 480),
+            metadata(true, 480),
+            metadata(true, 481),
+        ];
+        let (result, _) = drive(call, responses);
+        assert_eq!(result.map(|metadata| metadata.is_spent), Ok(true));
+    }
+
+    #[test]
+    fn shares_immutable_responses_between_nodes() {
+        let nodes = nodes(2);
+        let cache = crate::cache::LruCache::default();
+        let immutable = Cached(CachePolicy::Immutable);
+        let call = |first| {
+            Call::new(&immutable, &nodes, 0, None)
+                .unwrap()
+                .starting_at(first)
+                .with_cache(Some(&cache))
+        };
+
+        assert_eq!(
+            drive(call(0), vec![Ok(b"block".to_vec())]),
+            (Ok(b"block".to_vec()), vec![0])
+        );
+        assert_eq!(drive(call(1), vec![]), (Ok(b"block".to_vec()), vec![]));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn caches_mutable_responses_per_node() {
+        let nodes = nodes(2);
+        let cache = crate::cache::LruCache::default();
+        let mutable = Cached(CachePolicy::Ttl(Duration::from_secs(60)));
+        let call = |first| {
+            Call::new(&mutable, &nodes, 1, None)
+                .unwrap()
+                .starting_at(first)
+                .with_cache(Some(&cache))
+        };
+
+        assert_eq!(
+            drive(call(0), vec![Ok(b"info 0".to_vec())]),
+            (Ok(b"info 0".to_vec()), vec![0])
+        );
+        assert_eq!(drive(call(0), vec![]), (Ok(b"info 0".to_vec()), vec![]));
+        assert_eq!(
+            drive(call(1), vec![Ok(b"info 1".to_vec())]),
+            (Ok(b"info 1".to_vec()), vec![1])
+        );
+
+        // A response is cached for the node that answered, not the one asked first.
+        cache.clear();
+        assert_eq!(
+            drive(
+                call(0),
+                vec![Err(transport_error()), Ok(b"info 1".to_vec())]
+            ),
+            (Ok(b"info 1".to_vec()), vec![0, 1])
+        );
+        assert_eq!(drive(call(1), vec![]), (Ok(b"info 1".to_vec()), vec![]));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn pauses_rate_limited_nodes() {
+        let nodes = nodes(1);
+        let limiter = RateLimiter::new(None);
+        let mut call = Call::new(&Get, &nodes, 1, None)
+            .unwrap()
+            .with_rate_limiter(Some(&limiter));
+
+        match call.step() {
+            Step::Transmit(transmit) => assert_eq!(transmit.delay, Duration::ZERO),
+            Step::Done(result) => panic!("unexpected end: {:?}", result),
+        }
+        call.receive(Err(Error::RateLimited {
+            url: "https://node0.example.iota.org/get".into(),
+            retry_after: Some(30),
+        }));
+        match call.step() {
+            Step::Transmit(transmit) => assert!(transmit.delay > Duration::from_secs(29)),
+            Step::Done(result) => panic!("unexpected end: {:?}", result),
+        }
+    }
+
+    #[cfg(feature = "metrics")]
+    #[test]
+    fn records_requests_retries_errors_and_bytes() {
+        use crate::metrics::Recorder;
+        use spin::Mutex;
+
+        #[derive(Default)]
+        struct Counts {
+            requests: Vec<String>,
+            retries: Vec<String>,
+            errors: Vec<(String, Error)>,
+            bytes_sent: usize,
+            bytes_received: usize,
+        }
+
+        #[derive(Default)]
+        struct Recording(Mutex<Counts>);
+
+        impl Recorder for Recording {
+            fn request(&self, _endpoint: &str, node: &str) {
+                self.0.lock().requests.push(node.into());
+            }
+
+            fn retry(&self, _endpoint: &str, node: &str) {
+                self.0.lock().retries.push(node.into());
+            }
+
+            fn error(&self, _endpoint: &str, node: &str, error: &Error) {
+                self.0.lock().errors.push((node.into(), error.clone()));
+            }
+
+            fn bytes_sent(&self, _endpoint: &str, _node: &str, bytes: usize) {
+                self.0.lock().bytes_sent += bytes;
+            }
+
+            fn bytes_received(&self, _endpoint: &str, _node: &str, bytes: usize) {
+                self.0.lock().bytes_received += bytes;
+            }
+        }
+
+        let nodes = nodes(3);
+        let recording = Recording::default();
+        let endpoint = Post { idempotent: true };
+        let call = Call::new(&endpoint, &nodes, 2, None)
+            .unwrap()
+            .with_metrics(Some(&recording));
+
+        let responses = vec![Err(node_error(503)), Ok(b"done".to_vec())];
+        assert_eq!(drive(call, responses).0, Ok(b"done".to_vec()));
+
+        let counts = recording.0.lock();
+        assert_eq!(
+            counts.requests,
+            vec![
+                "https://node0.example.iota.org",
+                "https://node1.example.iota.org"
+            ]
+        );
+        assert_eq!(counts.retries, vec!["https://node1.example.iota.org"]);
+        assert_eq!(
+            counts.errors,
+            vec![("https://node0.example.iota.org".into(), node_error(503))]
+        );
+        assert_eq!(counts.bytes_sent, 2 * b"{}".len());
+        assert_eq!(counts.bytes_received, b"done".len());
+    }
+}
@@ -0,0 +1,77 @@
+//! Endpoints of the node control routes of Hornet.
+//!
+//! These routes are protected: nodes only accept them with [`Auth::Jwt`](crate::types::Auth::Jwt)
+//! of an operator.
+
+use super::{Endpoint, Request};
+use crate::types::{
+    ApiVersion, Capability, CreateSnapshotResponse, PruneDatabaseResponse, PruneTarget, Result,
+};
+use serde::Serialize;
+
+/// `POST /api/v2/control/database/prune`: prune the database of the node.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct PruneDatabase {
+    pub target: PruneTarget,
+}
+
+impl Endpoint for PruneDatabase {
+    type Output = PruneDatabaseResponse;
+
+    fn request(&self) -> Result<Request> {
+        Request::post_json("/api/v2/control/database/prune", &self.target)
+    }
+
+    fn decode(&self, body: &[u8]) -> Result<PruneDatabaseResponse> {
+        Ok(serde_json::from_slice(body)?)
+    }
+
+    fn capability(&self) -> Option<Capability> {
+        Some(ApiVersion::V2.into())
+    }
+}
+
+/// `POST /api/v2/control/snapshots/create`: create a snapshot of the ledger at a milestone.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize)]
+pub struct CreateSnapshot {
+    /// The milestone index to take the snapshot at.
+    pub index: u32,
+}
+
+impl Endpoint for CreateSnapshot {
+    type Output = CreateSnapshotResponse;
+
+    fn request(&self) -> Result<Request> {
+        Request::post_json("/api/v2/control/snapshots/create", self)
+    }
+
+    fn decode(&self, body: &[u8]) -> Result<CreateSnapshotResponse> {
+        Ok(serde_json::from_slice(body)?)
+    }
+
+    fn capability(&self) -> Option<Capability> {
+        Some(ApiVersion::V2.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::Method;
+
+    #[test]
+    fn requests_carry_json_bodies() {
+        let prune = PruneDatabase {
+            target: PruneTarget::Depth(100),
+        }
+        .request()
+        .unwrap();
+        assert_eq!(prune.method, Method::Post);
+        assert_eq!(prune.path, "/api/v2/control/database/prune");
+        assert_eq!(prune.body, br#"{"depth":100}"#);
+
+        let snapshot = CreateSnapshot { index: 4000 }.request().unwrap();
+        assert_eq!(snapshot.path, "/api/v2/control/snapshots/create");
+        assert_eq!(snapshot.body, br#"{"index":4000}"#);
+    }
+}
@@ -0,0 +1,735 @@
+//! Endpoints of [the Core REST API][core] (v2), served by nodes offering
+//! [`Capability::Core(ApiVersion::V2)`](Capability::Core).
+//!
+//! [core]: https://github.com/iotaledger/tips/pull/57
+
+use super::{without_fields, Endpoint, MediaType, Request, Response, MUTABLE_TTL};
+use crate::cache::CachePolicy;
+use crate::types::{
+    ApiVersion, Block, BlockId, BlockMetadata, Capability, InfoResponse, MilestonePayload,
+    MilestoneRef, OutputId, OutputMetadata, OutputResponse, Peer, ReceiptsResponse, Result,
+    RoutesResponse, SubmitBlockResponse, TipsResponse, TransactionId, TreasuryResponse,
+    UtxoChangesResponse,
+};
+use alloc::{string::String, vec::Vec};
+use serde::Serialize;
+
+/// `GET /health`: whether the node is healthy.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct Health;
+
+impl Endpoint for Health {
+    type Output = ();
+
+    fn request(&self) -> Result<Request> {
+        Ok(Request::get("/health"))
+    }
+
+    fn decode(&self, _body: &[u8]) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// `GET /api/v2/info`: general information about the node.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct Info;
+
+impl Endpoint for Info {
+    type Output = InfoResponse;
+
+    fn request(&self) -> Result<Request> {
+        Ok(Request::get("/api/v2/info"))
+    }
+
+    fn decode(&self, body: &[u8]) -> Result<InfoResponse> {
+        Ok(serde_json::from_slice(body)?)
+    }
+
+    fn capability(&self) -> Option<Capability> {
+        Some(ApiVersion::V2.into())
+    }
+
+    fn cache_policy(&self) -> CachePolicy {
+        CachePolicy::Ttl(MUTABLE_TTL)
+    }
+}
+
+/// `GET /api/routes`: the routes of the APIs offered by the node, e.g. `indexer/v1`.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct Routes;
+
+impl Endpoint for Routes {
+    type Output = RoutesResponse;
+
+    fn request(&self) -> Result<Request> {
+        Ok(Request::get("/api/routes"))
+    }
+
+    fn decode(&self, body: &[u8]) -> Result<RoutesResponse> {
+        Ok(serde_json::from_slice(body)?)
+    }
+
+    fn cache_policy(&self) -> CachePolicy {
+        CachePolicy::Ttl(MUTABLE_TTL)
+    }
+}
+
+/// `GET /api/v2/tips`: blocks not yet referenced by other blocks, to be used as parents.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct Tips;
+
+impl Endpoint for Tips {
+    type Output = TipsResponse;
+
+    fn request(&self) -> Result<Request> {
+        Ok(Request::get("/api/v2/tips"))
+    }
+
+    fn decode(&self, body: &[u8]) -> Result<TipsResponse> {
+        Ok(serde_json::from_slice(body)?)
+    }
+
+    fn capability(&self) -> Option<Capability> {
+        Some(ApiVersion::V2.into())
+    }
+}
+
+/// `POST /api/v2/blocks`: submit a block, returning its ID.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum SubmitBlock {
+    /// A block sent as JSON.
+    Json(Block),
+    /// A block already serialized with the binary serializer.
+    Binary(Vec<u8>),
+}
+
+impl From<Block> for SubmitBlock {
+    fn from(block: Block) -> Self {
+        Self::Json(block)
+    }
+}
+
+impl From<Vec<u8>> for SubmitBlock {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self::Binary(bytes)
+    }
+}
+
+impl Endpoint for SubmitBlock {
+    type Output = BlockId;
+
+    fn request(&self) -> Result<Request> {
+        Ok(match self {
+            Self::Json(block) => Request::post_json("/api/v2/blocks", block)?,
+            Self::Binary(bytes) => {
+                Request::post("/api/v2/blocks", bytes.clone()).content_type(MediaType::Binary)
+            }
+        })
+    }
+
+    fn decode(&self, body: &[u8]) -> Result<BlockId> {
+        Ok(serde_json::from_slice::<SubmitBlockResponse>(body)?.block_id)
+    }
+
+    /// The ID of the block is taken from the response body, or else from the `Location` header
+    /// (`/api/v2/blocks/{blockId}`).
+    fn decode_response(&self, response: &Response) -> Result<BlockId> {
+        match (self.decode(&response.body), &response.location) {
+            (Ok(block_id), _) => Ok(block_id),
+            (Err(err), Some(location)) => location
+                .trim_end_matches('/')
+                .rsplit('/')
+                .next()
+                .and_then(|id| id.parse().ok())
+                .ok_or(err),
+            (Err(err), None) => Err(err),
+        }
+    }
+
+    fn capability(&self) -> Option<Capability> {
+        Some(ApiVersion::V2.into())
+    }
+}
+
+/// `GET /api/v2/blocks/{blockId}`: a block, as JSON.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct GetBlock {
+    pub block_id: BlockId,
+}
+
+impl Endpoint for GetBlock {
+    type Output = Block;
+
+    fn request(&self) -> Result<Request> {
+        Ok(Request::get(alloc::format!(
+            "/api/v2/blocks/{}",
+            self.block_id
+        )))
+    }
+
+    fn decode(&self, body: &[u8]) -> Result<Block> {
+        Ok(serde_json::from_slice(body)?)
+    }
+
+    fn capability(&self) -> Option<Capability> {
+        Some(ApiVersion::V2.into())
+    }
+
+    fn cache_policy(&self) -> CachePolicy {
+        CachePolicy::Immutable
+    }
+}
+
+/// `GET /api/v2/blocks/{blockId}`: a block, serialized with the binary serializer.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct GetBlockRaw {
+    pub block_id: BlockId,
+}
+
+impl Endpoint for GetBlockRaw {
+    type Output = Vec<u8>;
+
+    fn request(&self) -> Result<Request> {
+        Ok(
+            Request::get(alloc::format!("/api/v2/blocks/{}", self.block_id))
+                .accept(MediaType::Binary),
+        )
+    }
+
+    fn decode(&self, body: &[u8]) -> Result<Vec<u8>> {
+        Ok(body.to_vec())
+    }
+
+    fn capability(&self) -> Option<Capability> {
+        Some(ApiVersion::V2.into())
+    }
+
+    fn cache_policy(&self) -> CachePolicy {
+        CachePolicy::Immutable
+    }
+}
+
+/// `GET /api/v2/blocks/{blockId}/metadata`: the state of a block in the Tangle and the ledger.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct GetBlockMetadata {
+    pub block_id: BlockId,
+}
+
+impl Endpoint for GetBlockMetadata {
+    type Output = BlockMetadata;
+
+    fn request(&self) -> Result<Request> {
+        Ok(Request::get(alloc::format!(
+            "/api/v2/blocks/{}/metadata",
+            self.block_id
+        )))
+    }
+
+    fn decode(&self, body: &[u8]) -> Result<BlockMetadata> {
+        Ok(serde_json::from_slice(body)?)
+    }
+
+    fn capability(&self) -> Option<Capability> {
+        Some(ApiVersion::V2.into())
+    }
+
+    /// Cached shortly: the state changes until the block is referenced. Waiting for the block to
+    /// be confirmed or promoting it polls the node itself.
+    fn cache_policy(&self) -> CachePolicy {
+        CachePolicy::Ttl(MUTABLE_TTL)
+    }
+}
+
+/// `GET /api/v2/outputs/{outputId}`: an output, with its state in the ledger.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct GetOutput {
+    pub output_id: OutputId,
+}
+
+impl Endpoint for GetOutput {
+    type Output = OutputResponse;
+
+    fn request(&self) -> Result<Request> {
+        Ok(Request::get(alloc::format!(
+            "/api/v2/outputs/{}",
+            self.output_id
+        )))
+    }
+
+    fn decode(&self, body: &[u8]) -> Result<OutputResponse> {
+        Ok(serde_json::from_slice(body)?)
+    }
+
+    fn capability(&self) -> Option<Capability> {
+        Some(ApiVersion::V2.into())
+    }
+
+    fn quorum(&self) -> bool {
+        true
+    }
+
+    fn quorum_key(&self, body: &[u8]) -> Vec<u8> {
+        without_fields(body, &["/metadata/ledgerIndex"])
+    }
+}
+
+/// `GET /api/v2/outputs/{outputId}/metadata`: the state of an output in the ledger.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct GetOutputMetadata {
+    pub output_id: OutputId,
+}
+
+impl Endpoint for GetOutputMetadata {
+    type Output = OutputMetadata;
+
+    fn request(&self) -> Result<Request> {
+        Ok(Request::get(alloc::format!(
+            "/api/v2/outputs/{}/metadata",
+            self.output_id
+        )))
+    }
+
+    fn decode(&self, body: &[u8]) -> Result<OutputMetadata> {
+        Ok(serde_json::from_slice(body)?)
+    }
+
+    fn capability(&self) -> Option<Capability> {
+        Some(ApiVersion::V2.into())
+    }
+
+    fn quorum(&self) -> bool {
+        true
+    }
+
+    fn quorum_key(&self, body: &[u8]) -> Vec<u8> {
+        without_fields(body, &["/ledgerIndex"])
+    }
+}
+
+/// `GET /api/v2/transactions/{transactionId}/included-block`: the block including a transaction
+/// in the ledger, as JSON.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct GetIncludedBlock {
+    pub transaction_id: TransactionId,
+}
+
+impl Endpoint for GetIncludedBlock {
+    type Output = Block;
+
+    fn request(&self) -> Result<Request> {
+        Ok(Request::get(alloc::format!(
+            "/api/v2/transactions/{}/included-block",
+            self.transaction_id
+        )))
+    }
+
+    fn decode(&self, body: &[u8]) -> Result<Block> {
+        Ok(serde_json::from_slice(body)?)
+    }
+
+    fn capability(&self) -> Option<Capability> {
+        Some(ApiVersion::V2.into())
+    }
+
+    fn cache_policy(&self) -> CachePolicy {
+        CachePolicy::Immutable
+    }
+}
+
+/// `GET /api/v2/transactions/{transactionId}/included-block`: the block including a transaction
+/// in the ledger, serialized with the binary serializer.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct GetIncludedBlockRaw {
+    pub transaction_id: TransactionId,
+}
+
+impl Endpoint for GetIncludedBlockRaw {
+    type Output = Vec<u8>;
+
+    fn request(&self) -> Result<Request> {
+        Ok(Request::get(alloc::format!(
+            "/api/v2/transactions/{}/included-block",
+            self.transaction_id
+        ))
+        .accept(MediaType::Binary))
+    }
+
+    fn decode(&self, body: &[u8]) -> Result<Vec<u8>> {
+        Ok(body.to_vec())
+    }
+
+    fn capability(&self) -> Option<Capability> {
+        Some(ApiVersion::V2.into())
+    }
+
+    fn cache_policy(&self) -> CachePolicy {
+        CachePolicy::Immutable
+    }
+}
+
+/// `GET /api/v2/transactions/{transactionId}/included-block/metadata`: the metadata of the block
+/// including a transaction.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct GetIncludedBlockMetadata {
+    pub transaction_id: TransactionId,
+}
+
+impl Endpoint for GetIncludedBlockMetadata {
+    type Output = BlockMetadata;
+
+    fn request(&self) -> Result<Request> {
+        Ok(Request::get(alloc::format!(
+            "/api/v2/transactions/{}/included-block/metadata",
+            self.transaction_id
+        )))
+    }
+
+    fn decode(&self, body: &[u8]) -> Result<BlockMetadata> {
+        Ok(serde_json::from_slice(body)?)
+    }
+
+    fn capability(&self) -> Option<Capability> {
+        Some(ApiVersion::V2.into())
+    }
+}
+
+/// `GET /api/v2/milestones/{milestoneId}` or `GET /api/v2/milestones/by-index/{index}`: a
+/// milestone payload, as JSON.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct GetMilestone {
+    pub milestone: MilestoneRef,
+}
+
+impl Endpoint for GetMilestone {
+    type Output = MilestonePayload;
+
+    fn request(&self) -> Result<Request> {
+        Ok(Request::get(milestone_path(self.milestone)))
+    }
+
+    fn decode(&self, body: &[u8]) -> Result<MilestonePayload> {
+        Ok(serde_json::from_slice(body)?)
+    }
+
+    fn capability(&self) -> Option<Capability> {
+        Some(ApiVersion::V2.into())
+    }
+
+    fn cache_policy(&self) -> CachePolicy {
+        CachePolicy::Immutable
+    }
+}
+
+/// `GET /api/v2/milestones/{milestoneId}` or `GET /api/v2/milestones/by-index/{index}`: a
+/// milestone payload, serialized with the binary serializer.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct GetMilestoneRaw {
+    pub milestone: MilestoneRef,
+}
+
+impl Endpoint for GetMilestoneRaw {
+    type Output = Vec<u8>;
+
+    fn request(&self) -> Result<Request> {
+        Ok(Request::get(milestone_path(self.milestone)).accept(MediaType::Binary))
+    }
+
+    fn decode(&self, body: &[u8]) -> Result<Vec<u8>> {
+        Ok(body.to_vec())
+    }
+
+    fn capability(&self) -> Option<Capability> {
+        Some(ApiVersion::V2.into())
+    }
+
+    fn cache_policy(&self) -> CachePolicy {
+        CachePolicy::Immutable
+    }
+}
+
+/// `GET /api/v2/milestones/{milestoneId}/utxo-changes` or
+/// `GET /api/v2/milestones/by-index/{index}/utxo-changes`: the outputs created and consumed by a
+/// milestone.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct GetUtxoChanges {
+    pub milestone: MilestoneRef,
+}
+
+impl Endpoint for GetUtxoChanges {
+    type Output = UtxoChangesResponse;
+
+    fn request(&self) -> Result<Request> {
+        Ok(Request::get(alloc::format!(
+            "{}/utxo-changes",
+            milestone_path(self.milestone)
+        )))
+    }
+
+    fn decode(&self, body: &[u8]) -> Result<UtxoChangesResponse> {
+        Ok(serde_json::from_slice(body)?)
+    }
+
+    fn capability(&self) -> Option<Capability> {
+        Some(ApiVersion::V2.into())
+    }
+
+    fn quorum(&self) -> bool {
+        true
+    }
+
+    fn cache_policy(&self) -> CachePolicy {
+        CachePolicy::Immutable
+    }
+}
+
+fn milestone_path(milestone: MilestoneRef) -> String {
+    match milestone {
+        MilestoneRef::Id(milestone_id) => alloc::format!("/api/v2/milestones/{}", milestone_id),
+        MilestoneRef::Index(index) => alloc::format!("/api/v2/milestones/by-index/{}", index),
+    }
+}
+
+/// `GET /api/v2/receipts`: all stored receipts.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct Receipts;
+
+impl Endpoint for Receipts {
+    type Output = ReceiptsResponse;
+
+    fn request(&self) -> Result<Request> {
+        Ok(Request::get("/api/v2/receipts"))
+    }
+
+    fn decode(&self, body: &[u8]) -> Result<ReceiptsResponse> {
+        Ok(serde_json::from_slice(body)?)
+    }
+
+    fn capability(&self) -> Option<Capability> {
+        Some(ApiVersion::V2.into())
+    }
+
+    fn quorum(&self) -> bool {
+        true
+    }
+
+    fn cache_policy(&self) -> CachePolicy {
+        CachePolicy::Ttl(MUTABLE_TTL)
+    }
+}
+
+/// `GET /api/v2/receipts/{migratedAt}`: the receipts of funds migrated at a legacy milestone.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct ReceiptsMigratedAt {
+    pub migrated_at: u32,
+}
+
+impl Endpoint for ReceiptsMigratedAt {
+    type Output = ReceiptsResponse;
+
+    fn request(&self) -> Result<Request> {
+        Ok(Request::get(alloc::format!(
+            "/api/v2/receipts/{}",
+            self.migrated_at
+        )))
+    }
+
+    fn decode(&self, body: &[u8]) -> Result<ReceiptsResponse> {
+        Ok(serde_json::from_slice(body)?)
+    }
+
+    fn capability(&self) -> Option<Capability> {
+        Some(ApiVersion::V2.into())
+    }
+
+    fn quorum(&self) -> bool {
+        true
+    }
+
+    fn cache_policy(&self) -> CachePolicy {
+        CachePolicy::Ttl(MUTABLE_TTL)
+    }
+}
+
+/// `GET /api/v2/treasury`: the current treasury output.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct Treasury;
+
+impl Endpoint for Treasury {
+    type Output = TreasuryResponse;
+
+    fn request(&self) -> Result<Request> {
+        Ok(Request::get("/api/v2/treasury"))
+    }
+
+    fn decode(&self, body: &[u8]) -> Result<TreasuryResponse> {
+        Ok(serde_json::from_slice(body)?)
+    }
+
+    fn capability(&self) -> Option<Capability> {
+        Some(ApiVersion::V2.into())
+    }
+
+    fn quorum(&self) -> bool {
+        true
+    }
+
+    fn cache_policy(&self) -> CachePolicy {
+        CachePolicy::Ttl(MUTABLE_TTL)
+    }
+}
+
+/// `GET /api/v2/peers`: the peers of the node.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct Peers;
+
+impl Endpoint for Peers {
+    type Output = Vec<Peer>;
+
+    fn request(&self) -> Result<Request> {
+        Ok(Request::get("/api/v2/peers"))
+    }
+
+    fn decode(&self, body: &[u8]) -> Result<Vec<Peer>> {
+        Ok(serde_json::from_slice(body)?)
+    }
+
+    fn capability(&self) -> Option<Capability> {
+        Some(ApiVersion::V2.into())
+    }
+}
+
+/// `GET /api/v2/peers/{peerId}`: a peer of the node.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct GetPeer {
+    pub peer_id: String,
+}
+
+impl Endpoint for GetPeer {
+    type Output = Peer;
+
+    fn request(&self) -> Result<Request> {
+        Ok(Request::get(alloc::format!(
+            "/api/v2/peers/{}",
+            self.peer_id
+        )))
+    }
+
+    fn decode(&self, body: &[u8]) -> Result<Peer> {
+        Ok(serde_json::from_slice(body)?)
+    }
+
+    fn capability(&self) -> Option<Capability> {
+        Some(ApiVersion::V2.into())
+    }
+}
+
+/// `POST /api/v2/peers`: add a peer to the node.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddPeer {
+    /// The multiaddress of the peer, including its identifier (`/p2p/...`).
+    pub multi_address: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alias: Option<String>,
+}
+
+impl Endpoint for AddPeer {
+    type Output = Peer;
+
+    fn request(&self) -> Result<Request> {
+        Request::post_json("/api/v2/peers", self)
+    }
+
+    fn decode(&self, body: &[u8]) -> Result<Peer> {
+        Ok(serde_json::from_slice(body)?)
+    }
+
+    fn capability(&self) -> Option<Capability> {
+        Some(ApiVersion::V2.into())
+    }
+}
+
+/// `DELETE /api/v2/peers/{peerId}`: remove a peer from the node.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct RemovePeer {
+    pub peer_id: String,
+}
+
+impl Endpoint for RemovePeer {
+    type Output = ();
+
+    fn request(&self) -> Result<Request> {
+        Ok(Request::delete(alloc::format!(
+            "/api/v2/peers/{}",
+            self.peer_id
+        )))
+    }
+
+    fn decode(&self, _body: &[u8]) -> Result<()> {
+        Ok(())
+    }
+
+    fn capability(&self) -> Option<Capability> {
+        Some(ApiVersion::V2.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::Method;
+    use crate::types::Error;
+
+    const BLOCK_ID: &str = "0x9a1a0b1f81a4c25d9d6d0e8c4e6a2d8d4e0f5c2b1a3e7d6c5b4a39281706f5e4";
+
+    #[test]
+    fn submitted_block_id_falls_back_to_location() {
+        let submit = SubmitBlock::Binary(alloc::vec![2]);
+        let block_id: BlockId = BLOCK_ID.parse().unwrap();
+
+        let from_body =
+            Response::from(alloc::format!(r#"{{"blockId":"{}"}}"#, BLOCK_ID).into_bytes());
+        assert_eq!(submit.decode_response(&from_body), Ok(block_id));
+
+        let from_location = Response {
+            body: Vec::new(),
+            location: Some(alloc::format!("/api/v2/blocks/{}", BLOCK_ID)),
+        };
+        assert_eq!(submit.decode_response(&from_location), Ok(block_id));
+
+        assert!(matches!(
+            submit.decode_response(&Response::default()),
+            Err(Error::ResponseError(_))
+        ));
+    }
+
+    #[test]
+    fn add_peer_request_carries_a_json_body() {
+        let request = AddPeer {
+            multi_address:
+                "/ip4/127.0.0.1/tcp/15600/p2p/12D3KooWCKWcTWevORKa2KEBputEGASvEBuDfRDSbe8t1DWugUmL"
+                    .into(),
+            alias: None,
+        }
+        .request()
+        .unwrap();
+
+        assert_eq!(request.method, Method::Post);
+        assert_eq!(
+            request.body,
+            br#"{"multiAddress":"/ip4/127.0.0.1/tcp/15600/p2p/12D3KooWCKWcTWevORKa2KEBputEGASvEBuDfRDSbe8t1DWugUmL"}"#
+        );
+    }
+
+    #[test]
+    fn block_metadata_is_not_subject_to_quorum() {
+        // Metadata changes as nodes catch up with milestones, so nodes need not agree on it.
+        let block_id: BlockId = BLOCK_ID.parse().unwrap();
+
+        assert!(!GetBlockMetadata { block_id }.quorum());
+        assert!(!GetIncludedBlockMetadata {
+            transaction_id: TransactionId([0; 32])
+        }
+        .quorum());
+    }
+}
@@ -0,0 +1,31 @@
+//! Endpoints of [the Faucet plugin API][faucet].
+//!
+//! [faucet]: https://github.com/iotaledger/inx-faucet
+
+use super::{Endpoint, Request};
+use crate::types::{Capability, FaucetEnqueueResponse, Result};
+use alloc::string::String;
+use serde::Serialize;
+
+/// `POST /api/faucet/v1/enqueue`: request funds from the faucet for an address.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize)]
+pub struct Enqueue {
+    /// The Bech32 address to send funds to.
+    pub address: String,
+}
+
+impl Endpoint for Enqueue {
+    type Output = FaucetEnqueueResponse;
+
+    fn request(&self) -> Result<Request> {
+        Request::post_json("/api/faucet/v1/enqueue", self)
+    }
+
+    fn decode(&self, body: &[u8]) -> Result<FaucetEnqueueResponse> {
+        Ok(serde_json::from_slice(body)?)
+    }
+
+    fn capability(&self) -> Option<Capability> {
+        Some(Capability::Faucet)
+    }
+}
@@ -0,0 +1,75 @@
+//! Endpoints of [the UTXO indexer plugin API][indexer], served by nodes offering
+//! [`Capability::Indexer`].
+//!
+//! [indexer]: https://github.com/iotaledger/tips/pull/62
+
+use super::{percent_encode, Endpoint, Request};
+use crate::types::{Capability, IndexerOutputsResponse, Result};
+use alloc::string::String;
+
+/// `GET /api/indexer/v1/outputs/basic`: IDs of unspent basic outputs, optionally only those
+/// unlockable by an address, a page at a time. The address and the cursor are percent-encoded
+/// into the query.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct BasicOutputs {
+    /// The Bech32 address the outputs are unlockable by.
+    pub address: Option<String>,
+    /// The cursor of the page to get, from the previous page.
+    pub cursor: Option<String>,
+}
+
+impl Endpoint for BasicOutputs {
+    type Output = IndexerOutputsResponse;
+
+    fn request(&self) -> Result<Request> {
+        let mut path = String::from("/api/indexer/v1/outputs/basic");
+        let query = [("address", &self.address), ("cursor", &self.cursor)];
+
+        for (index, (name, value)) in query
+            .iter()
+            .filter_map(|(name, value)| value.as_ref().map(|value| (name, value)))
+            .enumerate()
+        {
+            path.push(if index == 0 { '?' } else { '&' });
+            path.push_str(name);
+            path.push('=');
+            path.push_str(&percent_encode(value));
+        }
+
+        Ok(Request::get(path))
+    }
+
+    fn decode(&self, body: &[u8]) -> Result<IndexerOutputsResponse> {
+        Ok(serde_json::from_slice(body)?)
+    }
+
+    fn capability(&self) -> Option<Capability> {
+        Some(Capability::Indexer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn query_values_are_percent_encoded() {
+        let endpoint = BasicOutputs {
+            address: Some("rms1qp8h9augeh6tk3uvlxqfapuwv93atv63eqkpru029p6sgvr49eufyz7katr".into()),
+            cursor: Some("1653476460.0xee4f.1000".into()),
+        };
+        assert_eq!(
+            endpoint.request().unwrap().path,
+            "/api/indexer/v1/outputs/basic?address=rms1qp8h9augeh6tk3uvlxqfapuwv93atv63eqkpru029p6sgvr49eufyz7katr&cursor=1653476460.0xee4f.1000"
+        );
+
+        let endpoint = BasicOutputs {
+            address: None,
+            cursor: Some("a&address=b c/ü".into()),
+        };
+        assert_eq!(
+            endpoint.request().unwrap().path,
+            "/api/indexer/v1/outputs/basic?cursor=a%26address%3Db%20c%2F%C3%BC"
+        );
+    }
+}
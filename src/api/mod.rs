@@ -0,0 +1,307 @@
+//! Sans-IO descriptions of the APIs offered by nodes.
+//!
+//! Every API endpoint is described by a type implementing [`Endpoint`], which knows how to build
+//! the [`Request`] to send and how to decode the response body, but performs no I/O at all. A
+//! [`Call`] then walks through the configured nodes, telling its driver which HTTP request to
+//! perform next and deciding on the final result (retrying on another node, or collecting a
+//! quorum).
+//!
+//! [`Client`] and [`AsyncClient`] are such drivers, but any event loop can drive a [`Call`]:
+//!
+//! ```
+//! use atoi::api::{core::Info, Call, Method, Step};
+//! use atoi::types::Node;
+//!
+//! let nodes = ["https://example.iota.org".parse::<Node>().unwrap()];
+//! let mut call = Call::new(&Info, &nodes, 0, None).unwrap();
+//!
+//! let info = loop {
+//!     match call.step() {
+//!         Step::Transmit(transmit) => {
+//!             assert_eq!(transmit.method, Method::Get);
+//!             assert_eq!(transmit.url, "https://example.iota.org/api/v2/info");
+//!             // Perform the request with any HTTP library here.
+//!             # let response = atoi::comm::http::HttpClient::get(
+//!             #     &atoi::comm::http::DummyHttpClient::new(), &transmit.url, None, transmit.accept);
+//!             call.receive(response);
+//!         }
+//!         Step::Done(result) => break result,
+//!     }
+//! };
+//!
+//! assert!(info.is_ok());
+//! ```
+//!
+//! [`Client`]: crate::Client
+//! [`AsyncClient`]: crate::AsyncClient
+
+mod call;
+pub mod control;
+pub mod core;
+pub mod faucet;
+pub mod indexer;
+pub mod participation;
+#[cfg(feature = "std")]
+mod rate_limit;
+pub mod v1;
+
+pub use self::call::{Call, Step, Transmit};
+#[cfg(feature = "std")]
+pub use self::rate_limit::{RateLimiter, DEFAULT_PAUSE};
+
+use crate::cache::CachePolicy;
+use crate::types::{Capability, Error, Result};
+use ::core::time::Duration;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use serde::Serialize;
+
+/// Percent-encode `value` for a query string, leaving only unreserved characters as they are.
+pub(crate) fn percent_encode(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                encoded.push(byte as char)
+            }
+            byte => encoded.push_str(&alloc::format!("%{:02X}", byte)),
+        }
+    }
+
+    encoded
+}
+
+/// A JSON `body` without the fields at `pointers` (like `/metadata/ledgerIndex`), with keys in a
+/// canonical order, or `body` itself if it is not JSON.
+pub(crate) fn without_fields(body: &[u8], pointers: &[&str]) -> Vec<u8> {
+    let mut value: serde_json::Value = match serde_json::from_slice(body) {
+        Ok(value) => value,
+        Err(_) => return body.to_vec(),
+    };
+
+    for pointer in pointers {
+        let (parent, field) = pointer.rsplit_once('/').unwrap_or(("", pointer));
+        if let Some(serde_json::Value::Object(object)) = value.pointer_mut(parent) {
+            object.remove(field);
+        }
+    }
+
+    serde_json::to_vec(&value).unwrap_or_else(|_| body.to_vec())
+}
+
+/// How long responses of resources that may change are cached.
+pub(crate) const MUTABLE_TTL: Duration = Duration::from_secs(5);
+
+/// HTTP methods used by the node APIs.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum Method {
+    Get,
+    Post,
+    Delete,
+}
+
+/// Media types of request and response bodies.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum MediaType {
+    /// `application/json`.
+    Json,
+    /// `application/vnd.iota.serializer-v1`: the binary serialization of protocol objects.
+    Binary,
+}
+
+impl MediaType {
+    /// The value of `Content-Type` and `Accept` headers.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Json => "application/json",
+            Self::Binary => "application/vnd.iota.serializer-v1",
+        }
+    }
+}
+
+impl Default for MediaType {
+    fn default() -> Self {
+        Self::Json
+    }
+}
+
+/// Description of an HTTP request to a node, independent of the node.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct Request {
+    pub method: Method,
+    /// The path (and query) of the request, starting with `/`.
+    pub path: String,
+    /// The request body, empty if there is none.
+    pub body: Vec<u8>,
+    /// The media type of [`body`](Self::body).
+    pub content_type: MediaType,
+    /// The media type of the response asked for.
+    pub accept: MediaType,
+}
+
+impl Request {
+    /// A `GET` request to `path`.
+    pub fn get<P: Into<String>>(path: P) -> Self {
+        Self::new(Method::Get, path.into(), Vec::new())
+    }
+
+    /// A `POST` request to `path` with a JSON `body`.
+    pub fn post<P: Into<String>>(path: P, body: Vec<u8>) -> Self {
+        Self::new(Method::Post, path.into(), body)
+    }
+
+    /// A `POST` request to `path` with `value` serialized as a JSON body.
+    pub fn post_json<P: Into<String>, T: Serialize>(path: P, value: &T) -> Result<Self> {
+        let body = serde_json::to_vec(value).map_err(|err| Error::RequestError(err.to_string()))?;
+
+        Ok(Self::post(path, body))
+    }
+
+    /// A `DELETE` request to `path`.
+    pub fn delete<P: Into<String>>(path: P) -> Self {
+        Self::new(Method::Delete, path.into(), Vec::new())
+    }
+
+    /// Set the media type of the request body.
+    pub fn content_type(mut self, content_type: MediaType) -> Self {
+        self.content_type = content_type;
+        self
+    }
+
+    /// Ask for a response of another media type than JSON.
+    pub fn accept(mut self, accept: MediaType) -> Self {
+        self.accept = accept;
+        self
+    }
+
+    /// The key of the response in caches.
+    pub fn cache_key(&self) -> String {
+        match self.accept {
+            MediaType::Json => self.path.clone(),
+            accept => alloc::format!("{} ({})", self.path, accept.as_str()),
+        }
+    }
+
+    fn new(method: Method, path: String, body: Vec<u8>) -> Self {
+        Self {
+            method,
+            path,
+            body,
+            content_type: MediaType::Json,
+            accept: MediaType::Json,
+        }
+    }
+}
+
+/// A successful response of a node.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct Response {
+    pub body: Vec<u8>,
+    /// The value of the `Location` header, e.g. the path of a resource just created.
+    pub location: Option<String>,
+}
+
+impl From<Vec<u8>> for Response {
+    fn from(body: Vec<u8>) -> Self {
+        Self {
+            body,
+            location: None,
+        }
+    }
+}
+
+/// An API endpoint: how to request it, and how to interpret its response.
+pub trait Endpoint {
+    /// The decoded response.
+    type Output;
+
+    /// A name of the endpoint, used in diagnostics.
+    fn name(&self) -> &'static str {
+        ::core::any::type_name::<Self>()
+    }
+
+    /// Build the request to send.
+    fn request(&self) -> Result<Request>;
+
+    /// Decode a successful response body.
+    fn decode(&self, body: &[u8]) -> Result<Self::Output>;
+
+    /// Decode a successful response fresh from a node, including its headers. Responses served
+    /// from caches or compared for quorum are decoded with [`decode`](Self::decode) only.
+    fn decode_response(&self, response: &Response) -> Result<Self::Output> {
+        self.decode(&response.body)
+    }
+
+    /// Whether responses of this endpoint are subject to the quorum settings, i.e. whether they
+    /// query the ledger and should agree between nodes.
+    fn quorum(&self) -> bool {
+        false
+    }
+
+    /// The part of a response body nodes must agree on to reach quorum, by default all of it.
+    /// Responses with fields local to each node, like the ledger index it answered at, leave
+    /// them out.
+    fn quorum_key(&self, body: &[u8]) -> Vec<u8> {
+        body.to_vec()
+    }
+
+    /// Whether a `POST` request of this endpoint may be sent again after a failure, i.e. whether
+    /// sending it twice has the same effect as sending it once. `GET` and `DELETE` requests are
+    /// always considered idempotent.
+    fn idempotent(&self) -> bool {
+        false
+    }
+
+    /// The capability a node must offer to serve this endpoint, if any. Calls are then only sent
+    /// to nodes offering it.
+    fn capability(&self) -> Option<Capability> {
+        None
+    }
+
+    /// Whether and how long responses of this endpoint may be cached.
+    fn cache_policy(&self) -> CachePolicy {
+        CachePolicy::Never
+    }
+}
+
+/// An endpoint never answered from the cache, e.g. to poll a state until it changes.
+#[cfg(any(all(feature = "sync", feature = "std"), feature = "async"))]
+pub(crate) struct Uncached<'a, E>(pub(crate) &'a E);
+
+#[cfg(any(all(feature = "sync", feature = "std"), feature = "async"))]
+impl<E: Endpoint> Endpoint for Uncached<'_, E> {
+    type Output = E::Output;
+
+    fn name(&self) -> &'static str {
+        self.0.name()
+    }
+
+    fn request(&self) -> Result<Request> {
+        self.0.request()
+    }
+
+    fn decode(&self, body: &[u8]) -> Result<E::Output> {
+        self.0.decode(body)
+    }
+
+    fn decode_response(&self, response: &Response) -> Result<E::Output> {
+        self.0.decode_response(response)
+    }
+
+    fn quorum(&self) -> bool {
+        self.0.quorum()
+    }
+
+    fn quorum_key(&self, body: &[u8]) -> Vec<u8> {
+        self.0.quorum_key(body)
+    }
+
+    fn idempotent(&self) -> bool {
+        self.0.idempotent()
+    }
+
+    fn capability(&self) -> Option<Capability> {
+        self.0.capability()
+    }
+}
@@ -0,0 +1,27 @@
+//! Endpoints of [the participation plugin API][participation], served by nodes offering
+//! [`Capability::Participation`].
+//!
+//! [participation]: https://github.com/iotaledger/inx-participation
+
+use super::{Endpoint, Request};
+use crate::types::{Capability, ParticipationEventsResponse, Result};
+
+/// `GET /api/participation/v1/events`: the participation events tracked by the node.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct Events;
+
+impl Endpoint for Events {
+    type Output = ParticipationEventsResponse;
+
+    fn request(&self) -> Result<Request> {
+        Ok(Request::get("/api/participation/v1/events"))
+    }
+
+    fn decode(&self, body: &[u8]) -> Result<ParticipationEventsResponse> {
+        Ok(serde_json::from_slice(body)?)
+    }
+
+    fn capability(&self) -> Option<Capability> {
+        Some(Capability::Participation)
+    }
+}
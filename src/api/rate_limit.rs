@@ -0,0 +1,156 @@
+//! Client-side rate limiting of requests to each node.
+
+use crate::types::RateLimit;
+use alloc::vec::Vec;
+use core::time::Duration;
+use std::sync::Mutex;
+use std::time::Instant;
+
+/// How long a node is paused after responding `429 Too Many Requests` without `Retry-After`.
+pub const DEFAULT_PAUSE: Duration = Duration::from_secs(1);
+
+/// Token buckets limiting the rate of requests to each node, which also keep track of nodes paused
+/// after responding `429 Too Many Requests`.
+///
+/// Nodes are identified by their index in the node list of a client.
+#[derive(Debug, Default)]
+pub struct RateLimiter {
+    limit: Option<RateLimit>,
+    buckets: Mutex<Vec<Bucket>>,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Bucket {
+    tokens: f64,
+    updated_at: Instant,
+    paused_until: Option<Instant>,
+}
+
+impl RateLimiter {
+    /// A rate limiter allowing `limit` to every node, or any rate if not given.
+    pub fn new(limit: Option<RateLimit>) -> Self {
+        Self {
+            limit,
+            buckets: Default::default(),
+        }
+    }
+
+    /// The configured limit.
+    pub fn limit(&self) -> Option<RateLimit> {
+        self.limit
+    }
+
+    /// Take a token to send a request to `node`, returning how long to wait before sending it.
+    pub fn acquire(&self, node: usize) -> Duration {
+        let now = Instant::now();
+        let mut buckets = self.buckets.lock().unwrap_or_else(|err| err.into_inner());
+        let bucket = self.bucket(&mut buckets, node, now);
+
+        let paused = bucket
+            .paused_until
+            .map_or(Duration::ZERO, |until| until.saturating_duration_since(now));
+
+        let throttled = match self.limit {
+            Some(limit) if limit.requests_per_second > 0 => {
+                let rate = f64::from(limit.requests_per_second);
+                let elapsed = now.saturating_duration_since(bucket.updated_at);
+                let burst = f64::from(limit.burst.max(1));
+
+                bucket.tokens = (bucket.tokens + elapsed.as_secs_f64() * rate).min(burst);
+                bucket.updated_at = now;
+                bucket.tokens -= 1.0;
+
+                if bucket.tokens >= 0.0 {
+                    Duration::ZERO
+                } else {
+                    Duration::from_secs_f64(-bucket.tokens / rate)
+                }
+            }
+            _ => Duration::ZERO,
+        };
+
+        paused.max(throttled)
+    }
+
+    /// Stop sending requests to `node` for `duration`.
+    pub fn pause(&self, node: usize, duration: Duration) {
+        let now = Instant::now();
+        let mut buckets = self.buckets.lock().unwrap_or_else(|err| err.into_inner());
+        let bucket = self.bucket(&mut buckets, node, now);
+        let until = now + duration;
+
+        bucket.paused_until = Some(
+            bucket
+                .paused_until
+                .map_or(until, |paused| paused.max(until)),
+        );
+    }
+
+    fn bucket<'a>(
+        &self,
+        buckets: &'a mut Vec<Bucket>,
+        node: usize,
+        now: Instant,
+    ) -> &'a mut Bucket {
+        if buckets.len() <= node {
+            let tokens = self
+                .limit
+                .map_or(0.0, |limit| f64::from(limit.burst.max(1)));
+
+            buckets.resize(
+                node + 1,
+                Bucket {
+                    tokens,
+                    updated_at: now,
+                    paused_until: None,
+                },
+            );
+        }
+
+        &mut buckets[node]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn throttles_beyond_the_burst() {
+        let limiter = RateLimiter::new(Some(RateLimit {
+            requests_per_second: 10,
+            burst: 2,
+        }));
+
+        assert_eq!(limiter.acquire(0), Duration::ZERO);
+        assert_eq!(limiter.acquire(0), Duration::ZERO);
+
+        let delay = limiter.acquire(0);
+        assert!(delay > Duration::from_millis(50), "{:?}", delay);
+        assert!(delay <= Duration::from_millis(100), "{:?}", delay);
+
+        // Other nodes have buckets of their own.
+        assert_eq!(limiter.acquire(1), Duration::ZERO);
+    }
+
+    #[test]
+    fn never_throttles_without_limit() {
+        let limiter = RateLimiter::new(None);
+
+        for _ in 0..100 {
+            assert_eq!(limiter.acquire(0), Duration::ZERO);
+        }
+    }
+
+    #[test]
+    fn pauses_nodes() {
+        let limiter = RateLimiter::new(None);
+
+        limiter.pause(1, Duration::from_secs(30));
+        limiter.pause(1, Duration::from_secs(5));
+
+        assert_eq!(limiter.acquire(0), Duration::ZERO);
+        let delay = limiter.acquire(1);
+        assert!(delay > Duration::from_secs(29), "{:?}", delay);
+    }
+}
@@ -0,0 +1,110 @@
+//! Endpoints of the Chrysalis REST API (v1), served by nodes offering
+//! [`Capability::Core(ApiVersion::V1)`](Capability::Core).
+
+use super::{Endpoint, Request, MUTABLE_TTL};
+use crate::cache::CachePolicy;
+use crate::types::v1::{
+    InfoResponse, Message as MessageResponse, MessageId, MessagesByIndexResponse,
+};
+use crate::types::{ApiVersion, Capability, Result};
+use alloc::vec::Vec;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+
+/// Responses of the v1 APIs are wrapped into a `data` object.
+#[derive(Deserialize)]
+struct Envelope<T> {
+    data: T,
+}
+
+fn decode<T: DeserializeOwned>(body: &[u8]) -> Result<T> {
+    Ok(serde_json::from_slice::<Envelope<T>>(body)?.data)
+}
+
+/// `GET /api/v1/info`: general information about the node.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct Info;
+
+impl Endpoint for Info {
+    type Output = InfoResponse;
+
+    fn request(&self) -> Result<Request> {
+        Ok(Request::get("/api/v1/info"))
+    }
+
+    fn decode(&self, body: &[u8]) -> Result<InfoResponse> {
+        decode(body)
+    }
+
+    fn capability(&self) -> Option<Capability> {
+        Some(ApiVersion::V1.into())
+    }
+
+    fn cache_policy(&self) -> CachePolicy {
+        CachePolicy::Ttl(MUTABLE_TTL)
+    }
+}
+
+/// `GET /api/v1/messages/{messageId}`: a message by its ID.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct Message {
+    pub message_id: MessageId,
+}
+
+impl Endpoint for Message {
+    type Output = MessageResponse;
+
+    fn request(&self) -> Result<Request> {
+        Ok(Request::get(alloc::format!(
+            "/api/v1/messages/{}",
+            self.message_id
+        )))
+    }
+
+    fn decode(&self, body: &[u8]) -> Result<MessageResponse> {
+        decode(body)
+    }
+
+    fn capability(&self) -> Option<Capability> {
+        Some(ApiVersion::V1.into())
+    }
+
+    fn cache_policy(&self) -> CachePolicy {
+        CachePolicy::Immutable
+    }
+}
+
+/// `GET /api/v1/messages?index={index}`: IDs of the messages with an indexation payload of the
+/// given index.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct MessagesByIndex {
+    /// The raw index.
+    pub index: Vec<u8>,
+}
+
+impl Endpoint for MessagesByIndex {
+    type Output = MessagesByIndexResponse;
+
+    fn request(&self) -> Result<Request> {
+        Ok(Request::get(alloc::format!(
+            "/api/v1/messages?index={}",
+            hex::encode(&self.index)
+        )))
+    }
+
+    fn decode(&self, body: &[u8]) -> Result<MessagesByIndexResponse> {
+        decode(body)
+    }
+
+    fn quorum(&self) -> bool {
+        true
+    }
+
+    fn capability(&self) -> Option<Capability> {
+        Some(ApiVersion::V1.into())
+    }
+
+    fn cache_policy(&self) -> CachePolicy {
+        CachePolicy::Ttl(MUTABLE_TTL)
+    }
+}
@@ -0,0 +1,116 @@
+//! Probe IOTA nodes and rank them, to diagnose connectivity problems.
+//!
+//! ```text
+//! atoi-probe [--timeout-ms MS] [--manifest FILE] (--network NAME | URL...)
+//! ```
+
+use atoi::comm::http::CurlHttpClient;
+use atoi::types::{Capability, Error, NetworkManifest, Node, NodeProbe, Result};
+use atoi::Client;
+use std::sync::Arc;
+use std::time::Duration;
+
+const USAGE: &str =
+    "usage: atoi-probe [--timeout-ms MS] [--manifest FILE] (--network NAME | URL...)";
+
+fn main() {
+    match run(std::env::args().skip(1).collect()) {
+        Ok(true) => {}
+        Ok(false) => std::process::exit(1),
+        Err(err) => {
+            eprintln!("error: {:?}\n{}", err, USAGE);
+            std::process::exit(2);
+        }
+    }
+}
+
+/// Probe the nodes given by `args`, returning whether any of them is usable.
+fn run(args: Vec<String>) -> Result<bool> {
+    let mut timeout = Duration::from_secs(10);
+    let mut manifest = NetworkManifest::default();
+    let mut network = None;
+    let mut nodes: Vec<Node> = Vec::new();
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| Error::ConfigError(format!("missing value of `{}`", arg)))
+        };
+
+        match arg.as_str() {
+            "--timeout-ms" => {
+                let value = value()?;
+                let millis = value
+                    .parse()
+                    .map_err(|_| Error::ConfigError(format!("invalid timeout `{}`", value)))?;
+                timeout = Duration::from_millis(millis);
+            }
+            "--manifest" => manifest = NetworkManifest::from_file(value()?)?,
+            "--network" => network = Some(value()?),
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return Ok(true);
+            }
+            url => nodes.push(url.parse()?),
+        }
+    }
+
+    if let Some(name) = network {
+        let network = manifest
+            .network(&name)
+            .ok_or_else(|| Error::ConfigError(format!("unknown network `{}`", name)))?;
+        nodes.extend(network.nodes);
+    }
+    if nodes.is_empty() {
+        return Err(Error::MissingNode);
+    }
+
+    let mut curl = CurlHttpClient::new();
+    curl.timeout = Some(timeout);
+    let client = Client::builder().nodes(nodes).http(Arc::new(curl)).build();
+
+    let report = client.probe();
+
+    println!(
+        "{:>4}  {:<48} {:>9} {:>7} {:>4} {:>8} {:>4}  plugins / error",
+        "rank", "node", "latency", "healthy", "api", "sync lag", "pow"
+    );
+    for (rank, probe) in report.probes.iter().enumerate() {
+        println!(
+            "{:>4}  {:<48} {:>9} {:>7} {:>4} {:>8} {:>4}  {}",
+            rank + 1,
+            probe.node.url.as_str(),
+            probe.latency.map_or_else(
+                || "-".into(),
+                |latency| format!("{}ms", latency.as_millis())
+            ),
+            if probe.healthy { "yes" } else { "no" },
+            probe.api_version.map_or_else(
+                || "-".into(),
+                |version| format!("{:?}", version).to_lowercase()
+            ),
+            probe
+                .sync_lag
+                .map_or_else(|| "-".into(), |lag| lag.to_string()),
+            if probe.supports_pow() { "yes" } else { "no" },
+            details(probe),
+        );
+    }
+
+    Ok(report.best().is_some())
+}
+
+/// The plugins of a usable node, or why it is not usable.
+fn details(probe: &NodeProbe) -> String {
+    match (&probe.error, &probe.capabilities) {
+        (Some(err), _) => format!("{:?}", err),
+        (None, Some(capabilities)) => capabilities
+            .iter()
+            .filter(|capability| !matches!(capability, Capability::Core(_) | Capability::PoW))
+            .map(|capability| format!("{:?}", capability).to_lowercase())
+            .collect::<Vec<_>>()
+            .join(", "),
+        (None, None) => String::new(),
+    }
+}
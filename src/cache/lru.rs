@@ -0,0 +1,231 @@
+//! An in-memory cache evicting the least recently used entries.
+
+use super::{Cache, CacheStats};
+use alloc::collections::BTreeMap;
+use alloc::{string::String, vec::Vec};
+use core::time::Duration;
+
+/// The default number of entries kept by a [`LruCache`].
+pub const DEFAULT_CAPACITY: usize = 256;
+
+/// An in-memory cache evicting the least recently used entries once full.
+///
+/// Expiring entries need a clock, so without the `std` feature, responses with a time-to-live are
+/// not cached at all.
+///
+/// ```
+/// use atoi::cache::{Cache, LruCache};
+///
+/// let cache = LruCache::new(1);
+///
+/// cache.insert("/a".into(), b"a".to_vec(), None);
+/// cache.insert("/b".into(), b"b".to_vec(), None);
+///
+/// assert_eq!(cache.get("/a"), None);
+/// assert_eq!(cache.get("/b"), Some(b"b".to_vec()));
+/// assert_eq!(cache.stats().hits, 1);
+/// assert_eq!(cache.stats().misses, 1);
+/// ```
+#[derive(Debug)]
+pub struct LruCache {
+    capacity: usize,
+    state: spin::Mutex<State>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    entries: BTreeMap<String, Entry>,
+    /// Keys by the tick of their last use, the least recently used first.
+    recency: BTreeMap<u64, String>,
+    tick: u64,
+    stats: CacheStats,
+}
+
+#[derive(Debug)]
+struct Entry {
+    value: Vec<u8>,
+    last_used: u64,
+    #[cfg(feature = "std")]
+    expires_at: Option<std::time::Instant>,
+}
+
+impl Entry {
+    fn is_expired(&self) -> bool {
+        #[cfg(feature = "std")]
+        return self
+            .expires_at
+            .map_or(false, |expires_at| expires_at <= std::time::Instant::now());
+        #[cfg(not(feature = "std"))]
+        return false;
+    }
+}
+
+impl LruCache {
+    /// A cache holding at most `capacity` responses.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            state: Default::default(),
+        }
+    }
+
+    /// The number of responses currently stored.
+    pub fn len(&self) -> usize {
+        self.state.lock().entries.len()
+    }
+
+    /// Whether no response is stored.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Default for LruCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_CAPACITY)
+    }
+}
+
+impl State {
+    fn next_tick(&mut self) -> u64 {
+        self.tick += 1;
+        self.tick
+    }
+
+    fn remove(&mut self, key: &str) {
+        if let Some(entry) = self.entries.remove(key) {
+            self.recency.remove(&entry.last_used);
+        }
+    }
+}
+
+impl Cache for LruCache {
+    fn get(&self, key: &str) -> Option<Vec<u8>> {
+        let mut state = self.state.lock();
+
+        match state.entries.get(key).map(Entry::is_expired) {
+            Some(false) => {
+                let tick = state.next_tick();
+                let state = &mut *state;
+                let entry = state.entries.get_mut(key)?;
+
+                state.recency.remove(&entry.last_used);
+                state.recency.insert(tick, key.into());
+                entry.last_used = tick;
+                state.stats.hits += 1;
+
+                Some(entry.value.clone())
+            }
+            Some(true) => {
+                state.remove(key);
+                state.stats.misses += 1;
+                None
+            }
+            None => {
+                state.stats.misses += 1;
+                None
+            }
+        }
+    }
+
+    fn insert(&self, key: String, value: Vec<u8>, ttl: Option<Duration>) {
+        #[cfg(feature = "std")]
+        let expires_at = ttl.map(|ttl| std::time::Instant::now() + ttl);
+        #[cfg(not(feature = "std"))]
+        if ttl.is_some() {
+            return;
+        }
+
+        if self.capacity == 0 {
+            return;
+        }
+
+        let mut state = self.state.lock();
+        let tick = state.next_tick();
+
+        state.remove(&key);
+
+        while state.entries.len() >= self.capacity {
+            match state.recency.iter().next().map(|(_, key)| key.clone()) {
+                Some(oldest) => state.remove(&oldest),
+                None => break,
+            }
+        }
+
+        state.recency.insert(tick, key.clone());
+        state.entries.insert(
+            key,
+            Entry {
+                value,
+                last_used: tick,
+                #[cfg(feature = "std")]
+                expires_at,
+            },
+        );
+    }
+
+    fn clear(&self) {
+        let mut state = self.state.lock();
+
+        state.entries.clear();
+        state.recency.clear();
+    }
+
+    fn stats(&self) -> CacheStats {
+        self.state.lock().stats
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn evicts_the_least_recently_used_response() {
+        let cache = LruCache::new(2);
+
+        cache.insert("/a".into(), b"a".to_vec(), None);
+        cache.insert("/b".into(), b"b".to_vec(), None);
+        assert_eq!(cache.get("/a"), Some(b"a".to_vec()));
+        cache.insert("/c".into(), b"c".to_vec(), None);
+
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.get("/b"), None);
+        assert_eq!(cache.get("/a"), Some(b"a".to_vec()));
+        assert_eq!(cache.get("/c"), Some(b"c".to_vec()));
+        assert_eq!(cache.stats(), CacheStats { hits: 3, misses: 1 });
+    }
+
+    #[test]
+    fn replaces_responses_of_the_same_key() {
+        let cache = LruCache::new(2);
+
+        cache.insert("/a".into(), b"old".to_vec(), None);
+        cache.insert("/a".into(), b"new".to_vec(), None);
+
+        assert_eq!(cache.len(), 1);
+        assert_eq!(cache.get("/a"), Some(b"new".to_vec()));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn expires_responses_after_their_ttl() {
+        let cache = LruCache::new(2);
+
+        cache.insert("/a".into(), b"a".to_vec(), Some(Duration::ZERO));
+        cache.insert("/b".into(), b"b".to_vec(), Some(Duration::from_secs(60)));
+
+        assert_eq!(cache.get("/a"), None);
+        assert_eq!(cache.len(), 1);
+        assert_eq!(cache.get("/b"), Some(b"b".to_vec()));
+    }
+
+    #[test]
+    fn stores_nothing_without_capacity() {
+        let cache = LruCache::new(0);
+
+        cache.insert("/a".into(), b"a".to_vec(), None);
+
+        assert!(cache.is_empty());
+    }
+}
@@ -0,0 +1,53 @@
+//! Caches of responses from nodes.
+//!
+//! Many resources on the Tangle never change once their ID is known (e.g. blocks, transactions
+//! and milestones), so they don't need to be fetched again. Each [`Endpoint`] declares with a
+//! [`CachePolicy`] whether and how long its responses may be kept; clients with a [`Cache`] then
+//! serve repeated requests from it.
+//!
+//! [`Endpoint`]: crate::api::Endpoint
+
+mod lru;
+
+pub use self::lru::{LruCache, DEFAULT_CAPACITY};
+
+use alloc::{string::String, vec::Vec};
+use core::time::Duration;
+
+/// How long the response of an endpoint may be cached.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum CachePolicy {
+    /// The response must not be cached.
+    Never,
+    /// The response never changes and is the same on every node, so it is cached forever and
+    /// shared between nodes.
+    Immutable,
+    /// The response may change, and differ between nodes, so it is cached for the given duration
+    /// for each node.
+    Ttl(Duration),
+}
+
+/// Counters of a [`Cache`].
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct CacheStats {
+    /// Number of lookups answered from the cache.
+    pub hits: usize,
+    /// Number of lookups not found in the cache (or expired).
+    pub misses: usize,
+}
+
+/// A store of response bodies, keyed by request path, and by node for responses that are not
+/// [immutable](CachePolicy::Immutable).
+pub trait Cache: Send + Sync {
+    /// Look up a response body, counting a hit or a miss.
+    fn get(&self, key: &str) -> Option<Vec<u8>>;
+
+    /// Store a response body, valid for `ttl` if given, or forever.
+    fn insert(&self, key: String, value: Vec<u8>, ttl: Option<Duration>);
+
+    /// Remove every stored response.
+    fn clear(&self);
+
+    /// The hit and miss counters.
+    fn stats(&self) -> CacheStats;
+}
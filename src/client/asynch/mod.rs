@@ -3,21 +3,131 @@
 mod private;
 mod public;
 
-use crate::comm::http::AsyncHttpClient;
-use crate::types::Node;
-use alloc::boxed::Box;
+#[cfg(feature = "std")]
+use crate::api::RateLimiter;
+use crate::cache::{Cache, LruCache};
+use crate::client::discovery::Discovered;
+use crate::comm::http::{AsyncHttpClient, DummyHttpClient};
+use crate::comm::timer::Timer;
+#[cfg(feature = "metrics")]
+use crate::metrics::Recorder;
+use crate::types::{Backend, CancellationToken, Config, Error, Node, Quorum, Result};
+use alloc::{sync::Arc, vec::Vec};
+use core::time::Duration;
 use typed_builder::TypedBuilder;
 
 /// The entry point of IOTA, with asynchronous interfaces.
-#[derive(Default, TypedBuilder)]
+#[derive(Clone, Default, TypedBuilder)]
 #[builder(field_defaults(default, setter(strip_option)))]
 pub struct AsyncClient {
-    pub node: Option<Node>,
-    pub http: Option<Box<dyn AsyncHttpClient>>,
+    /// Nodes to connect to, in the order of preference.
+    #[builder(setter(!strip_option, into))]
+    pub nodes: Vec<Node>,
+    pub http: Option<Arc<dyn AsyncHttpClient>>,
+    /// How many times a failed request is retried. Each retry is sent to the next node.
+    #[builder(setter(!strip_option))]
+    pub retries: usize,
+    /// Quorum settings for requests querying the ledger.
+    pub quorum: Option<Quorum>,
+    /// Limits of the rate of requests to each node. Nodes responding `429 Too Many Requests` are
+    /// paused even without limits.
+    #[cfg(feature = "std")]
+    #[builder(setter(!strip_option, into))]
+    pub rate_limiter: Arc<RateLimiter>,
+    /// Cache of responses. Without one, every call is sent to a node.
+    pub cache: Option<Arc<dyn Cache>>,
+    /// Receiver of metrics about requests.
+    #[cfg(feature = "metrics")]
+    pub metrics: Option<Arc<dyn Recorder>>,
+    /// Deadline of every call, unless overridden by [`CallOptions::timeout`].
+    pub timeout: Option<Duration>,
+    /// Maximum number of requests in flight during batch calls like [`call_many`], defaulting to
+    /// [`DEFAULT_CONCURRENCY`](crate::client::DEFAULT_CONCURRENCY).
+    ///
+    /// [`call_many`]: Self::call_many
+    pub concurrency: Option<usize>,
+    /// The timer enforcing deadlines. Without one, a [`ThreadTimer`] is used with the `std`
+    /// feature, and deadlines cannot be used otherwise.
+    ///
+    /// [`ThreadTimer`]: crate::comm::timer::ThreadTimer
+    pub timer: Option<Arc<dyn Timer>>,
+    /// Capabilities discovered on nodes so far.
+    #[builder(default, setter(skip))]
+    discovered: Arc<Discovered>,
+}
+
+/// Options of a single call made by an [`AsyncClient`].
+#[derive(Clone, Debug, Default, TypedBuilder)]
+#[builder(field_defaults(default, setter(strip_option)))]
+pub struct CallOptions {
+    /// Deadline of the call, overriding [`AsyncClient::timeout`].
+    pub timeout: Option<Duration>,
+    /// A token to cancel the call with.
+    pub cancel: Option<CancellationToken>,
 }
 
 impl AsyncClient {
     pub fn new() -> Self {
         Default::default()
     }
+
+    /// Construct a client from a [`Config`], overridden by environment variables with the `std`
+    /// feature (see [`Config::apply_env`]).
+    pub fn from_config(config: Config) -> Result<Self> {
+        #[cfg(feature = "std")]
+        let config = {
+            let mut config = config;
+            config.apply_env()?;
+            config
+        };
+
+        let http: Option<Arc<dyn AsyncHttpClient>> = match config.backend {
+            Some(Backend::Dummy) => Some(Arc::new(DummyHttpClient::new())),
+            Some(backend) => {
+                return Err(Error::ConfigError(alloc::format!(
+                    "the `{:?}` backend has no asynchronous interfaces",
+                    backend
+                )))
+            }
+            None => None,
+        };
+
+        Ok(Self {
+            nodes: config.resolved_nodes(),
+            http,
+            retries: config.retries,
+            quorum: config.quorum,
+            #[cfg(feature = "std")]
+            rate_limiter: Arc::new(RateLimiter::new(config.rate_limit)),
+            cache: match config.cache_capacity {
+                Some(0) | None => None,
+                Some(capacity) => Some(Arc::new(LruCache::new(capacity))),
+            },
+            #[cfg(feature = "metrics")]
+            metrics: None,
+            timeout: config.timeout(),
+            concurrency: config.concurrency,
+            timer: None,
+            discovered: Default::default(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AsyncClient;
+    use crate::types::Config;
+    use core::time::Duration;
+
+    #[test]
+    fn from_config_keeps_the_timeout() {
+        let config = Config {
+            timeout_ms: Some(1500),
+            ..Default::default()
+        };
+
+        let client = AsyncClient::from_config(config).unwrap();
+
+        assert_eq!(client.timeout, Some(Duration::from_millis(1500)));
+    }
 }
@@ -0,0 +1,122 @@
+//! Discovery of the capabilities of nodes.
+
+use crate::api::core::{Info, Routes};
+use crate::api::v1;
+use crate::client::discovery;
+use crate::types::{
+    ApiVersion, CancellationToken, Capabilities, Capability, Error, NodeInfo, Result,
+};
+use crate::AsyncClient;
+use alloc::vec::Vec;
+use futures_util::future;
+
+impl AsyncClient {
+    /// The capabilities of the node at `index`, queried once and remembered afterwards. Failures
+    /// are remembered for a short while.
+    pub(crate) async fn capabilities_at(
+        &self,
+        index: usize,
+        cancel: &CancellationToken,
+    ) -> Result<Capabilities> {
+        let url = &self.nodes[index].url;
+
+        if let Some(discovery) = self.discovered.get(url) {
+            return discovery;
+        }
+
+        self.discover_at(index, cancel)
+            .await
+            .map(|(_, capabilities)| capabilities)
+    }
+
+    /// Query the info and capabilities of the node at `index`, and remember the capabilities.
+    pub(crate) async fn discover_at(
+        &self,
+        index: usize,
+        cancel: &CancellationToken,
+    ) -> Result<(NodeInfo, Capabilities)> {
+        // Ask the node itself, as responses differ between nodes. Chrysalis nodes only answer
+        // the v1 info, but unreachable nodes are not asked twice.
+        let discovery = match self.call_on(&Info, index, cancel).await {
+            Ok(info) => {
+                let mut capabilities = Capabilities::from(&info);
+                // Older nodes have no `/api/routes`; their plugins are listed in the info.
+                if let Ok(routes) = self.call_on(&Routes, index, cancel).await {
+                    routes
+                        .routes
+                        .iter()
+                        .for_each(|route| capabilities.add_route(route));
+                }
+                Ok((NodeInfo::Stardust(info), capabilities))
+            }
+            Err(err @ Error::HttpClientError(_)) => Err(err),
+            Err(err) => match self.call_on(&v1::Info, index, cancel).await {
+                Ok(info) => {
+                    let capabilities = Capabilities::from(&info);
+                    Ok((NodeInfo::Chrysalis(info), capabilities))
+                }
+                Err(_) => Err(err),
+            },
+        };
+
+        self.discovered.insert(
+            self.nodes[index].url.clone(),
+            discovery.clone().map(|(_, capabilities)| capabilities),
+        );
+
+        discovery
+    }
+
+    /// The newest API generation spoken by the preferred reachable node.
+    pub(crate) async fn preferred_api_version(
+        &self,
+        cancel: &CancellationToken,
+    ) -> Result<ApiVersion> {
+        let mut discovered = Vec::new();
+
+        // Stop at the first reachable node.
+        for index in 0..self.nodes.len() {
+            let capabilities = self.capabilities_at(index, cancel).await;
+            let reachable = capabilities.is_ok();
+
+            discovered.push(capabilities);
+            if reachable {
+                break;
+            }
+        }
+
+        discovery::preferred_api_version(discovered)
+    }
+
+    /// Indices of up to `wanted` nodes offering `capability`, in the order of preference. Nodes
+    /// are discovered `wanted` at a time concurrently, until enough of them are found.
+    pub(crate) async fn nodes_offering(
+        &self,
+        capability: Capability,
+        wanted: usize,
+        cancel: &CancellationToken,
+    ) -> Result<Vec<usize>> {
+        let indices: Vec<usize> = (0..self.nodes.len()).collect();
+        let mut discovered = Vec::with_capacity(indices.len());
+        let mut found = 0;
+
+        for wave in indices.chunks(wanted.max(1)) {
+            let results = future::join_all(
+                wave.iter()
+                    .map(|&index| self.capabilities_at(index, cancel)),
+            )
+            .await;
+
+            found += results
+                .iter()
+                .filter(|result| matches!(result, Ok(capabilities) if capabilities.supports(capability)))
+                .count();
+            discovered.extend(results);
+            if found >= wanted {
+                break;
+            }
+        }
+
+        discovery::offering(capability, discovered, wanted)
+    }
+}
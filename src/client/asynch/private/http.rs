@@ -1,23 +1,328 @@
-//! High-level HTTP methods sending request to selected nodes.
+//! Driver performing the HTTP requests of [API calls][Call] on selected nodes.
+//!
+//! [Call]: crate::api::Call
 
+use crate::api::{Call, Endpoint, Method, Step};
+use crate::client::{CallOptions, DEFAULT_CONCURRENCY};
 use crate::comm::http::AsyncHttpClient;
-use crate::types::{Error, Node, Result};
+use crate::comm::timer::Timer;
+use crate::types::{CancellationToken, Error, Quorum, Result};
 use crate::AsyncClient;
 use alloc::vec::Vec;
+use core::future::Future;
+use core::task::Poll;
+use futures_util::stream::{self, StreamExt};
 
 impl AsyncClient {
-    fn http_and_node(&self) -> Result<(&dyn AsyncHttpClient, &Node)> {
-        match (&self.http, &self.node) {
-            (Some(http), Some(node)) => Ok((http.as_ref(), node)),
-            (Some(_), None) => Err(Error::MissingNode),
-            (None, Some(_)) | (None, None) => Err(Error::MissingHttpClient),
+    /// Call an API endpoint on the configured nodes.
+    pub async fn call<E: Endpoint>(&self, endpoint: &E) -> Result<E::Output> {
+        self.call_with(endpoint, &CallOptions::default()).await
+    }
+
+    /// Call an API endpoint on the configured nodes, with a deadline or cancellation.
+    ///
+    /// Once the deadline passes or `options.cancel` is cancelled, the HTTP client is told to abort
+    /// the ongoing request, and [`Error::Timeout`] or [`Error::Cancelled`] is returned.
+    pub async fn call_with<E: Endpoint>(
+        &self,
+        endpoint: &E,
+        options: &CallOptions,
+    ) -> Result<E::Output> {
+        self.call_at(endpoint, options, 0).await
+    }
+
+    /// Call many API endpoints on the configured nodes concurrently, with at most
+    /// [`concurrency`](Self::concurrency) requests in flight.
+    ///
+    /// Calls are spread over the nodes: the `n`-th call starts with the `n`-th node. Results are
+    /// returned in the order of `endpoints`.
+    pub async fn call_many<E, I>(&self, endpoints: I) -> Vec<Result<E::Output>>
+    where
+        E: Endpoint,
+        I: IntoIterator<Item = E>,
+    {
+        let options = CallOptions::default();
+        let concurrency = self.concurrency.unwrap_or(DEFAULT_CONCURRENCY).max(1);
+
+        stream::iter(endpoints.into_iter().enumerate())
+            .map(|(index, endpoint)| {
+                let options = &options;
+                async move { self.call_at(&endpoint, options, index).await }
+            })
+            .buffered(concurrency)
+            .collect()
+            .await
+    }
+
+    async fn call_at<E: Endpoint>(
+        &self,
+        endpoint: &E,
+        options: &CallOptions,
+        first_node: usize,
+    ) -> Result<E::Output> {
+        let cancel = Self::token(options);
+
+        self.guarded(options, &cancel, self.route(endpoint, &cancel, first_node))
+            .await
+    }
+
+    /// The token cancelling a call made with `options`.
+    pub(crate) fn token(options: &CallOptions) -> CancellationToken {
+        options
+            .cancel
+            .as_ref()
+            .map_or_else(CancellationToken::new, CancellationToken::child_token)
+    }
+
+    /// Run `work` until it finishes, `cancel` is cancelled, or the deadline of `options` passes.
+    pub(crate) async fn guarded<T, F>(
+        &self,
+        options: &CallOptions,
+        cancel: &CancellationToken,
+        work: F,
+    ) -> Result<T>
+    where
+        F: Future<Output = Result<T>>,
+    {
+        let mut deadline = match options.timeout.or(self.timeout) {
+            Some(timeout) => Some(self.timer()?.sleep(timeout)),
+            None => None,
+        };
+
+        let cancelled = cancel.cancelled();
+        futures_util::pin_mut!(work, cancelled);
+
+        futures_util::future::poll_fn(|cx| {
+            if cancelled.as_mut().poll(cx).is_ready() {
+                return Poll::Ready(Err(Error::Cancelled));
+            }
+
+            if let Poll::Ready(result) = work.as_mut().poll(cx) {
+                return Poll::Ready(result);
+            }
+
+            match deadline.as_mut().map(|deadline| deadline.as_mut().poll(cx)) {
+                Some(Poll::Ready(())) => {
+                    cancel.cancel();
+                    Poll::Ready(Err(Error::Timeout))
+                }
+                _ => Poll::Pending,
+            }
+        })
+        .await
+    }
+
+    /// The timer of the client, falling back to [`ThreadTimer`](crate::comm::timer::ThreadTimer)
+    /// with the `std` feature.
+    pub(crate) fn timer(&self) -> Result<&dyn Timer> {
+        match &self.timer {
+            Some(timer) => Ok(timer.as_ref()),
+            #[cfg(feature = "std")]
+            None => Ok(&crate::comm::timer::ThreadTimer),
+            #[cfg(not(feature = "std"))]
+            None => Err(Error::MissingTimer),
+        }
+    }
+
+    /// Send the call to the nodes able to serve it.
+    async fn route<E: Endpoint>(
+        &self,
+        endpoint: &E,
+        cancel: &CancellationToken,
+        first_node: usize,
+    ) -> Result<E::Output> {
+        let call = self
+            .prepare(endpoint, self.retries, self.quorum)?
+            .starting_at(first_node)
+            .with_cache(self.cache.as_deref());
+        let call = match endpoint.capability() {
+            Some(capability) => {
+                let nodes = self
+                    .nodes_offering(capability, call.nodes_needed(), cancel)
+                    .await?;
+                call.restricted_to(nodes)
+            }
+            None => call,
+        };
+
+        self.run(call, cancel).await
+    }
+
+    /// Call an API endpoint on the node at `index` only, within the deadline of the client, e.g.
+    /// to manage that node.
+    pub(crate) async fn call_on_node<E: Endpoint>(
+        &self,
+        endpoint: &E,
+        index: usize,
+    ) -> Result<E::Output> {
+        let options = &CallOptions::default();
+        let cancel = Self::token(options);
+
+        self.guarded(options, &cancel, self.call_on(endpoint, index, &cancel))
+            .await
+    }
+
+    /// Call an API endpoint on the node at `index` only, without retries nor the cache shared by
+    /// all nodes.
+    pub(crate) async fn call_on<E: Endpoint>(
+        &self,
+        endpoint: &E,
+        index: usize,
+        cancel: &CancellationToken,
+    ) -> Result<E::Output> {
+        if index >= self.nodes.len() {
+            return Err(Error::MissingNode);
         }
+
+        self.run(
+            self.prepare(endpoint, 0, None)?
+                .restricted_to(alloc::vec![index]),
+            cancel,
+        )
+        .await
+    }
+
+    /// Start a call on the configured nodes, with the rate limits and metrics of the client.
+    fn prepare<'a, E: Endpoint>(
+        &'a self,
+        endpoint: &'a E,
+        retries: usize,
+        quorum: Option<Quorum>,
+    ) -> Result<Call<'a, E>> {
+        let call = Call::new(endpoint, &self.nodes, retries, quorum)?;
+        #[cfg(feature = "std")]
+        let call = call.with_rate_limiter(Some(&self.rate_limiter));
+        #[cfg(feature = "metrics")]
+        let call = call.with_metrics(self.metrics.as_deref());
+
+        Ok(call)
+    }
+
+    /// Drive `call` to its end, unless `cancel` is cancelled.
+    async fn run<E: Endpoint>(
+        &self,
+        mut call: Call<'_, E>,
+        cancel: &CancellationToken,
+    ) -> Result<E::Output> {
+        let http: &dyn AsyncHttpClient = self.http.as_deref().ok_or(Error::MissingHttpClient)?;
+
+        loop {
+            match call.step() {
+                Step::Transmit(_) if cancel.is_cancelled() => return Err(Error::Cancelled),
+                Step::Transmit(transmit) => {
+                    if !transmit.delay.is_zero() {
+                        self.timer()?.sleep(transmit.delay).await;
+                    }
+
+                    let response = async {
+                        match transmit.method {
+                            Method::Get => {
+                                http.get(&transmit.url, transmit.auth, transmit.accept, cancel)
+                                    .await
+                            }
+                            Method::Post => {
+                                http.post(
+                                    &transmit.url,
+                                    transmit.auth,
+                                    &transmit.body,
+                                    transmit.content_type,
+                                    cancel,
+                                )
+                                .await
+                            }
+                            Method::Delete => {
+                                http.delete(&transmit.url, transmit.auth, cancel).await
+                            }
+                        }
+                    };
+                    #[cfg(feature = "tracing")]
+                    let response =
+                        tracing::Instrument::instrument(response, call.attempt_span().clone());
+
+                    call.receive(response.await);
+                }
+                Step::Done(result) => return result,
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::api::core::Tips;
+    use crate::api::Method;
+    use crate::client::CallOptions;
+    use crate::comm::http::fake::FakeHttpClient;
+    use crate::comm::timer::{ThreadTimer, Timer};
+    use crate::types::{CancellationToken, Error, Node};
+    use crate::AsyncClient;
+    use alloc::sync::Arc;
+    use alloc::vec;
+    use core::time::Duration;
+
+    const NODE: &str = "https://node.example.iota.org";
+
+    fn client(http: &Arc<FakeHttpClient>, timeout: Option<Duration>) -> AsyncClient {
+        http.node(NODE, &["core/v2"])
+            .hang(Method::Get, &format!("{}/api/v2/tips", NODE));
+
+        let client = AsyncClient::builder()
+            .nodes(vec![NODE.parse::<Node>().unwrap()])
+            .http(http.clone())
+            .retries(0)
+            .build();
+        AsyncClient { timeout, ..client }
+    }
+
+    #[async_std::test]
+    async fn calls_time_out_after_the_deadline() {
+        let http = Arc::new(FakeHttpClient::new());
+        let client = client(&http, Some(Duration::from_millis(20)));
+
+        assert_eq!(client.call(&Tips).await, Err(Error::Timeout));
+        assert_eq!(http.count(&format!("{}/api/v2/tips", NODE)), 1);
+    }
+
+    #[async_std::test]
+    async fn call_deadlines_override_the_client_timeout() {
+        let http = Arc::new(FakeHttpClient::new());
+        let client = client(&http, Some(Duration::from_secs(3600)));
+        let options = CallOptions::builder()
+            .timeout(Duration::from_millis(20))
+            .build();
+
+        assert_eq!(client.call_with(&Tips, &options).await, Err(Error::Timeout));
+    }
+
+    #[async_std::test]
+    async fn cancelled_calls_stop_waiting() {
+        let http = Arc::new(FakeHttpClient::new());
+        let client = client(&http, None);
+        let token = CancellationToken::new();
+        let options = CallOptions::builder().cancel(token.clone()).build();
+
+        let (result, ()) = futures_util::future::join(client.call_with(&Tips, &options), async {
+            ThreadTimer.sleep(Duration::from_millis(20)).await;
+            token.cancel();
+        })
+        .await;
+
+        assert_eq!(result, Err(Error::Cancelled));
+        assert_eq!(http.count(&format!("{}/api/v2/tips", NODE)), 1);
     }
 
-    pub(crate) async fn http_get(&self, path: &str) -> Result<Vec<u8>> {
-        let (http, node) = self.http_and_node()?;
-        let url = node.url.clone() + path;
+    #[async_std::test]
+    async fn calls_cancelled_beforehand_send_nothing() {
+        let http = Arc::new(FakeHttpClient::new());
+        let client = client(&http, None);
+        let token = CancellationToken::new();
+        token.cancel();
+        let options = CallOptions::builder().cancel(token).build();
 
-        http.get(&url, node.auth.as_ref()).await
+        assert_eq!(
+            client.call_with(&Tips, &options).await,
+            Err(Error::Cancelled)
+        );
+        assert_eq!(http.count(&format!("{}/api/v2/tips", NODE)), 0);
     }
 }
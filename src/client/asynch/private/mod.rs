@@ -2,4 +2,5 @@
 //!
 //! [AsyncClient]: super::AsyncClient
 
+mod discovery;
 mod http;
@@ -0,0 +1,207 @@
+//! Waiting for confirmations with [AsyncClient].
+
+use crate::api::core::{GetBlockMetadata, GetIncludedBlockMetadata};
+use crate::api::{Endpoint, Uncached};
+use crate::client::CallOptions;
+use crate::types::{
+    BlockId, BlockMetadata, CancellationToken, Confirmation, Result, TransactionId, WaitOptions,
+};
+use crate::AsyncClient;
+
+impl AsyncClient {
+    /// Wait until a milestone references `block_id`, polling its metadata as told by `options`.
+    /// The block may be unknown to the node at first, and failures of the node are waited out.
+    /// Pauses between polls are taken with the timer of the client.
+    pub async fn wait_for_block_confirmation(
+        &self,
+        block_id: BlockId,
+        options: &WaitOptions,
+    ) -> Result<Confirmation> {
+        self.wait_for_block_confirmation_with(block_id, options, &CancellationToken::new())
+            .await
+    }
+
+    /// Like [`wait_for_block_confirmation`](Self::wait_for_block_confirmation), until `cancel`
+    /// is cancelled.
+    pub async fn wait_for_block_confirmation_with(
+        &self,
+        block_id: BlockId,
+        options: &WaitOptions,
+        cancel: &CancellationToken,
+    ) -> Result<Confirmation> {
+        self.poll_until_confirmed(&GetBlockMetadata { block_id }, options, cancel)
+            .await
+    }
+
+    /// Wait until a milestone references a block including `transaction_id`, polling the metadata
+    /// of that block as told by `options`. The transaction may be unknown to the node at first,
+    /// and failures of the node are waited out.
+    pub async fn wait_for_transaction_confirmation(
+        &self,
+        transaction_id: TransactionId,
+        options: &WaitOptions,
+    ) -> Result<Confirmation> {
+        self.wait_for_transaction_confirmation_with(
+            transaction_id,
+            options,
+            &CancellationToken::new(),
+        )
+        .await
+    }
+
+    /// Like [`wait_for_transaction_confirmation`](Self::wait_for_transaction_confirmation),
+    /// until `cancel` is cancelled.
+    pub async fn wait_for_transaction_confirmation_with(
+        &self,
+        transaction_id: TransactionId,
+        options: &WaitOptions,
+        cancel: &CancellationToken,
+    ) -> Result<Confirmation> {
+        self.poll_until_confirmed(
+            &GetIncludedBlockMetadata { transaction_id },
+            options,
+            cancel,
+        )
+        .await
+    }
+
+    /// Poll `endpoint` until the block is referenced. Every poll is a call guarded by the same
+    /// token as the wait, so requests in flight are aborted once the wait ends.
+    async fn poll_until_confirmed<E>(
+        &self,
+        endpoint: &E,
+        options: &WaitOptions,
+        cancel: &CancellationToken,
+    ) -> Result<Confirmation>
+    where
+        E: Endpoint<Output = BlockMetadata>,
+    {
+        let timer = self.timer()?;
+        let wait = CallOptions::builder()
+            .timeout(options.timeout)
+            .cancel(cancel.clone())
+            .build();
+        let cancel = Self::token(&wait);
+        let poll = CallOptions::builder().cancel(cancel.clone()).build();
+
+        self.guarded(&wait, &cancel, async {
+            loop {
+                match self.call_with(&Uncached(endpoint), &poll).await {
+                    Ok(metadata) => {
+                        if let Some(confirmation) = Confirmation::from_metadata(&metadata) {
+                            return Ok(confirmation);
+                        }
+                    }
+                    // Not spread to the node yet, or the node is unavailable: poll again later.
+                    Err(err) if err.may_clear() => {}
+                    Err(err) => return Err(err),
+                }
+                timer.sleep(options.interval).await;
+            }
+        })
+        .await
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::api::Method;
+    use crate::comm::http::fake::FakeHttpClient;
+    use crate::comm::timer::{ThreadTimer, Timer};
+    use crate::types::{CancellationToken, Error, Node, TransactionId, WaitOptions};
+    use crate::AsyncClient;
+    use alloc::sync::Arc;
+    use alloc::vec;
+    use core::time::Duration;
+
+    const NODE: &str = "https://node.example.iota.org";
+    const BLOCK_ID: &str = "0x9a1a0b1f81a4c25d9d6d0e8c4e6a2d8d4e0f5c2b1a3e7d6c5b4a39281706f5e4";
+
+    fn client(http: &Arc<FakeHttpClient>) -> AsyncClient {
+        http.node(NODE, &["core/v2"]);
+        AsyncClient::builder()
+            .nodes(vec![NODE.parse::<Node>().unwrap()])
+            .http(http.clone())
+            .retries(0)
+            .build()
+    }
+
+    fn options(timeout_ms: u64) -> WaitOptions {
+        WaitOptions::builder()
+            .interval(Duration::from_millis(1))
+            .timeout(Duration::from_millis(timeout_ms))
+            .build()
+    }
+
+    #[async_std::test]
+    async fn times_out_while_the_transaction_is_unknown() {
+        let http = Arc::new(FakeHttpClient::new());
+        let client = client(&http);
+
+        assert_eq!(
+            client
+                .wait_for_transaction_confirmation(TransactionId([7; 32]), &options(20))
+                .await,
+            Err(Error::Timeout)
+        );
+        assert!(http.requests().len() > 2);
+    }
+
+    #[async_std::test]
+    async fn waits_out_unknown_blocks_and_node_failures() {
+        let http = Arc::new(FakeHttpClient::new());
+        let url = alloc::format!("{}/api/v2/blocks/{}/metadata", NODE, BLOCK_ID);
+        http.respond(Method::Get, &url, 404, "")
+            .respond(Method::Get, &url, 503, "")
+            .respond(
+                Method::Get,
+                &url,
+                200,
+                &alloc::format!(
+                    r#"{{"blockId":"{}","parents":[],"isSolid":true,"referencedByMilestoneIndex":471}}"#,
+                    BLOCK_ID
+                ),
+            );
+        let client = client(&http);
+
+        let confirmation = client
+            .wait_for_block_confirmation(BLOCK_ID.parse().unwrap(), &options(10_000))
+            .await
+            .unwrap();
+
+        assert_eq!(confirmation.milestone_index, 471);
+        assert_eq!(http.count(&url), 3);
+    }
+
+    #[async_std::test]
+    async fn deadlines_and_cancellation_abort_polls_in_flight() {
+        let http = Arc::new(FakeHttpClient::new());
+        let url = alloc::format!("{}/api/v2/blocks/{}/metadata", NODE, BLOCK_ID);
+        http.hang(Method::Get, &url);
+        let client = client(&http);
+
+        assert_eq!(
+            client
+                .wait_for_block_confirmation(BLOCK_ID.parse().unwrap(), &options(20))
+                .await,
+            Err(Error::Timeout)
+        );
+
+        let token = CancellationToken::new();
+        let (result, ()) = futures_util::future::join(
+            client.wait_for_block_confirmation_with(
+                BLOCK_ID.parse().unwrap(),
+                &options(10_000),
+                &token,
+            ),
+            async {
+                ThreadTimer.sleep(Duration::from_millis(20)).await;
+                token.cancel();
+            },
+        )
+        .await;
+
+        assert_eq!(result, Err(Error::Cancelled));
+        assert_eq!(http.count(&url), 2);
+    }
+}
@@ -0,0 +1,22 @@
+//! Implementation of the node control routes of Hornet for [AsyncClient].
+
+use crate::api::control::{CreateSnapshot, PruneDatabase};
+use crate::types::{CreateSnapshotResponse, PruneDatabaseResponse, PruneTarget, Result};
+use crate::AsyncClient;
+
+impl AsyncClient {
+    /// Prune the database of the node at `node`, an index into [`nodes`](Self::nodes), up to
+    /// `target`.
+    pub async fn prune_database(
+        &self,
+        node: usize,
+        target: PruneTarget,
+    ) -> Result<PruneDatabaseResponse> {
+        self.call_on_node(&PruneDatabase { target }, node).await
+    }
+
+    /// Make the node at `node` create a snapshot of the ledger at milestone `index`.
+    pub async fn create_snapshot(&self, node: usize, index: u32) -> Result<CreateSnapshotResponse> {
+        self.call_on_node(&CreateSnapshot { index }, node).await
+    }
+}
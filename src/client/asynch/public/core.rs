@@ -2,18 +2,287 @@
 //!
 //! [core]: https://github.com/iotaledger/tips/pull/57
 
-use crate::types::{InfoResponse, Result};
+use crate::api::core::{
+    AddPeer, GetBlock, GetBlockMetadata, GetBlockRaw, GetIncludedBlock, GetIncludedBlockMetadata,
+    GetIncludedBlockRaw, GetMilestone, GetMilestoneRaw, GetOutput, GetOutputMetadata, GetPeer,
+    GetUtxoChanges, Health, Info, Peers, Receipts, ReceiptsMigratedAt, RemovePeer, Routes,
+    SubmitBlock, Tips, Treasury,
+};
+use crate::api::v1;
+use crate::client::CallOptions;
+use crate::types::{
+    ApiVersion, Block, BlockId, BlockMetadata, Capabilities, Error, MilestonePayload, MilestoneRef,
+    NodeErrorKind, NodeInfo, OutputId, OutputMetadata, OutputResponse, Parents, Peer,
+    ReceiptsResponse, Result, RoutesResponse, TipsResponse, TransactionId, TransactionStatus,
+    TreasuryResponse, UtxoChangesResponse,
+};
 use crate::AsyncClient;
+use alloc::vec::Vec;
+use futures_util::future;
 
 impl AsyncClient {
     pub async fn health(&self) -> Result<()> {
-        self.http_get("/health").await?;
-        Ok(())
+        self.call(&Health).await
     }
 
-    pub async fn info(&self) -> Result<InfoResponse> {
-        Ok(serde_json::from_slice(
-            &self.http_get("/api/v2/info").await?,
-        )?)
+    /// General information about the preferred node, from the APIs of the generation it speaks.
+    /// The info is only asked of nodes of that generation, the preferred one first.
+    pub async fn info(&self) -> Result<NodeInfo> {
+        match self.api_version().await? {
+            ApiVersion::V1 => self.call(&v1::Info).await.map(NodeInfo::Chrysalis),
+            ApiVersion::V2 => self.call(&Info).await.map(NodeInfo::Stardust),
+        }
+    }
+
+    pub async fn routes(&self) -> Result<RoutesResponse> {
+        self.call(&Routes).await
+    }
+
+    /// The API generation spoken by the preferred node that can be reached.
+    pub async fn api_version(&self) -> Result<ApiVersion> {
+        let options = CallOptions::default();
+        let cancel = Self::token(&options);
+
+        self.guarded(&options, &cancel, self.preferred_api_version(&cancel))
+            .await
+    }
+
+    /// The capabilities of every configured node, in order. Nodes are only queried the first time.
+    pub async fn capabilities(&self) -> Vec<Result<Capabilities>> {
+        let options = &CallOptions::default();
+
+        future::join_all((0..self.nodes.len()).map(|index| async move {
+            let cancel = Self::token(options);
+
+            self.guarded(options, &cancel, self.capabilities_at(index, &cancel))
+                .await
+        }))
+        .await
+    }
+
+    pub async fn tips(&self) -> Result<TipsResponse> {
+        self.call(&Tips).await
+    }
+
+    /// Select parents for a new block among the current tips.
+    pub async fn parents(&self) -> Result<Parents> {
+        Parents::new(self.tips().await?.tips)
+    }
+
+    /// Keep `parents` if selected at most [`Parents::MAX_AGE`] ago, or else select new ones.
+    pub async fn refresh_parents(&self, parents: Parents) -> Result<Parents> {
+        if parents.is_stale(Parents::MAX_AGE) {
+            self.parents().await
+        } else {
+            Ok(parents)
+        }
+    }
+
+    /// Submit a block, either a [`Block`](crate::types::Block) sent as JSON or bytes of a block
+    /// serialized with the binary serializer, and return its ID.
+    ///
+    /// If the node rejects the block, its reason is returned in
+    /// [`Error::NodeError`](crate::types::Error::NodeError).
+    pub async fn submit_block<B: Into<SubmitBlock>>(&self, block: B) -> Result<BlockId> {
+        let block = block.into();
+
+        if let SubmitBlock::Json(block) = &block {
+            block.validate()?;
+        }
+
+        self.call(&block).await
+    }
+
+    pub async fn get_block(&self, block_id: BlockId) -> Result<Block> {
+        self.call(&GetBlock { block_id }).await
+    }
+
+    /// A block serialized with the binary serializer.
+    pub async fn get_block_raw(&self, block_id: BlockId) -> Result<Vec<u8>> {
+        self.call(&GetBlockRaw { block_id }).await
+    }
+
+    pub async fn get_block_metadata(&self, block_id: BlockId) -> Result<BlockMetadata> {
+        self.call(&GetBlockMetadata { block_id }).await
+    }
+
+    pub async fn get_output(&self, output_id: OutputId) -> Result<OutputResponse> {
+        self.call(&GetOutput { output_id }).await
+    }
+
+    pub async fn get_output_metadata(&self, output_id: OutputId) -> Result<OutputMetadata> {
+        self.call(&GetOutputMetadata { output_id }).await
+    }
+
+    /// The metadata of many outputs, e.g. to check whether they are
+    /// [spent](OutputMetadata::is_spent), fetched concurrently. Results are returned in the order
+    /// of `output_ids`.
+    pub async fn get_outputs_metadata<I>(&self, output_ids: I) -> Vec<Result<OutputMetadata>>
+    where
+        I: IntoIterator<Item = OutputId>,
+    {
+        self.call_many(
+            output_ids
+                .into_iter()
+                .map(|output_id| GetOutputMetadata { output_id }),
+        )
+        .await
+    }
+
+    /// The block including a transaction in the ledger.
+    pub async fn get_included_block(&self, transaction_id: TransactionId) -> Result<Block> {
+        self.call(&GetIncludedBlock { transaction_id }).await
+    }
+
+    /// The block including a transaction in the ledger, serialized with the binary serializer.
+    pub async fn get_included_block_raw(&self, transaction_id: TransactionId) -> Result<Vec<u8>> {
+        self.call(&GetIncludedBlockRaw { transaction_id }).await
+    }
+
+    /// The state of a transaction in the ledger, or [`TransactionStatus::Unknown`] if the node
+    /// knows no block including it.
+    pub async fn transaction_status(
+        &self,
+        transaction_id: TransactionId,
+    ) -> Result<TransactionStatus> {
+        match self
+            .call(&GetIncludedBlockMetadata { transaction_id })
+            .await
+        {
+            Ok(metadata) => TransactionStatus::try_from(&metadata),
+            Err(Error::NodeError {
+                kind: NodeErrorKind::NotFound,
+                ..
+            }) => Ok(TransactionStatus::Unknown),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// A milestone payload, by its [ID](crate::types::MilestoneId) or by its index.
+    pub async fn get_milestone<M: Into<MilestoneRef>>(
+        &self,
+        milestone: M,
+    ) -> Result<MilestonePayload> {
+        self.call(&GetMilestone {
+            milestone: milestone.into(),
+        })
+        .await
+    }
+
+    /// A milestone payload, serialized with the binary serializer.
+    pub async fn get_milestone_raw<M: Into<MilestoneRef>>(&self, milestone: M) -> Result<Vec<u8>> {
+        self.call(&GetMilestoneRaw {
+            milestone: milestone.into(),
+        })
+        .await
+    }
+
+    /// The outputs created and consumed by a milestone, to follow the ledger milestone by
+    /// milestone.
+    pub async fn get_utxo_changes<M: Into<MilestoneRef>>(
+        &self,
+        milestone: M,
+    ) -> Result<UtxoChangesResponse> {
+        self.call(&GetUtxoChanges {
+            milestone: milestone.into(),
+        })
+        .await
+    }
+
+    /// All receipts of funds migrated from the legacy network.
+    pub async fn receipts(&self) -> Result<ReceiptsResponse> {
+        self.call(&Receipts).await
+    }
+
+    /// The receipts of funds migrated at the legacy milestone `migrated_at`.
+    pub async fn receipts_migrated_at(&self, migrated_at: u32) -> Result<ReceiptsResponse> {
+        self.call(&ReceiptsMigratedAt { migrated_at }).await
+    }
+
+    /// The current treasury output.
+    pub async fn treasury(&self) -> Result<TreasuryResponse> {
+        self.call(&Treasury).await
+    }
+
+    /// The peers of the node at `node`, an index into [`nodes`](Self::nodes).
+    ///
+    /// Peers are specific to each node, so peer methods are sent to the given node only, without
+    /// retries. Nodes usually protect these routes with [`Auth::Jwt`](crate::types::Auth::Jwt).
+    pub async fn peers(&self, node: usize) -> Result<Vec<Peer>> {
+        self.call_on_node(&Peers, node).await
+    }
+
+    /// A peer of the node at `node`.
+    pub async fn peer(&self, node: usize, peer_id: &str) -> Result<Peer> {
+        self.call_on_node(
+            &GetPeer {
+                peer_id: peer_id.into(),
+            },
+            node,
+        )
+        .await
+    }
+
+    /// Add a peer reachable at `multi_address` to the node at `node`.
+    pub async fn add_peer(
+        &self,
+        node: usize,
+        multi_address: &str,
+        alias: Option<&str>,
+    ) -> Result<Peer> {
+        self.call_on_node(
+            &AddPeer {
+                multi_address: multi_address.into(),
+                alias: alias.map(Into::into),
+            },
+            node,
+        )
+        .await
+    }
+
+    /// Remove a peer from the node at `node`.
+    pub async fn remove_peer(&self, node: usize, peer_id: &str) -> Result<()> {
+        self.call_on_node(
+            &RemovePeer {
+                peer_id: peer_id.into(),
+            },
+            node,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::comm::http::fake::FakeHttpClient;
+    use crate::types::{Node, TransactionId, TransactionStatus};
+    use crate::AsyncClient;
+    use alloc::sync::Arc;
+    use alloc::vec;
+
+    #[async_std::test]
+    async fn transaction_status_of_unknown_transaction() {
+        let http = Arc::new(FakeHttpClient::new());
+        http.node("https://node.example.iota.org", &["core/v2"]);
+        let client = AsyncClient::builder()
+            .nodes(vec!["https://node.example.iota.org"
+                .parse::<Node>()
+                .unwrap()])
+            .http(http.clone())
+            .retries(0)
+            .build();
+        let transaction_id: TransactionId =
+            "0x5f6e7d8c9b0a1f2e3d4c5b6a7980f1e2d3c4b5a697887766554433221100ffee"
+                .parse()
+                .unwrap();
+
+        assert_eq!(
+            client.transaction_status(transaction_id).await,
+            Ok(TransactionStatus::Unknown)
+        );
+        assert_eq!(
+            http.requests().last().map(|(_, url)| url.as_str()),
+            Some("https://node.example.iota.org/api/v2/transactions/0x5f6e7d8c9b0a1f2e3d4c5b6a7980f1e2d3c4b5a697887766554433221100ffee/included-block/metadata")
+        );
     }
 }
@@ -2,6 +2,25 @@
 //!
 //! [event]: https://github.com/iotaledger/tips/blob/main/tips/TIP-0016/tip-0016.md
 
+use crate::client::discovery;
+use crate::client::CallOptions;
+use crate::types::{Capability, Result};
 use crate::AsyncClient;
+use alloc::string::String;
 
-impl AsyncClient {}
+impl AsyncClient {
+    /// The WebSocket URL of the MQTT event API on the preferred node offering it.
+    pub async fn mqtt_url(&self) -> Result<String> {
+        let options = CallOptions::default();
+        let cancel = Self::token(&options);
+        let index = self
+            .guarded(
+                &options,
+                &cancel,
+                self.nodes_offering(Capability::Mqtt, 1, &cancel),
+            )
+            .await?[0];
+
+        Ok(discovery::mqtt_url(&self.nodes[index].url))
+    }
+}
@@ -1,7 +1,17 @@
 //! Implementation of [the Faucet plugin API][faucet] for [AsyncClient].
 //!
-//! [faucet]: #
+//! [faucet]: https://github.com/iotaledger/inx-faucet
 
+use crate::api::faucet::Enqueue;
+use crate::types::{FaucetEnqueueResponse, Result};
 use crate::AsyncClient;
 
-impl AsyncClient {}
+impl AsyncClient {
+    /// Request funds from the faucet for `address`, on a node offering the faucet.
+    pub async fn request_funds(&self, address: &str) -> Result<FaucetEnqueueResponse> {
+        self.call(&Enqueue {
+            address: address.into(),
+        })
+        .await
+    }
+}
@@ -2,6 +2,22 @@
 //!
 //! [indexer]: https://github.com/iotaledger/tips/pull/62
 
+use crate::api::indexer::BasicOutputs;
+use crate::types::{IndexerOutputsResponse, Result};
 use crate::AsyncClient;
 
-impl AsyncClient {}
+impl AsyncClient {
+    /// IDs of unspent basic outputs unlockable by `address`, on a node offering the indexer. Pass
+    /// the cursor of a page to get the next one.
+    pub async fn basic_outputs(
+        &self,
+        address: &str,
+        cursor: Option<&str>,
+    ) -> Result<IndexerOutputsResponse> {
+        self.call(&BasicOutputs {
+            address: Some(address.into()),
+            cursor: cursor.map(Into::into),
+        })
+        .await
+    }
+}
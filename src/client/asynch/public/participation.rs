@@ -0,0 +1,14 @@
+//! Implementation of [the participation plugin API][participation] for [AsyncClient].
+//!
+//! [participation]: https://github.com/iotaledger/inx-participation
+
+use crate::api::participation::Events;
+use crate::types::{ParticipationEventsResponse, Result};
+use crate::AsyncClient;
+
+impl AsyncClient {
+    /// The participation events tracked by a node offering the participation plugin.
+    pub async fn participation_events(&self) -> Result<ParticipationEventsResponse> {
+        self.call(&Events).await
+    }
+}
@@ -0,0 +1,62 @@
+//! Probing of the configured nodes of [AsyncClient].
+
+use crate::api::core::Health;
+use crate::client::CallOptions;
+use crate::types::{CancellationToken, Error, NodeProbe, ProbeReport};
+use crate::AsyncClient;
+use futures_util::future;
+use std::time::Instant;
+
+impl AsyncClient {
+    /// Probe every configured node for its latency, health, sync lag and capabilities,
+    /// concurrently, and rank them. Every node is probed within [`timeout`](Self::timeout).
+    pub async fn probe(&self) -> ProbeReport {
+        let options = &CallOptions::default();
+
+        let probes = future::join_all((0..self.nodes.len()).map(|index| async move {
+            let cancel = Self::token(options);
+            let probe = self
+                .guarded(options, &cancel, async {
+                    Ok(self.probe_at(index, &cancel).await)
+                })
+                .await;
+
+            probe.unwrap_or_else(|err| {
+                let mut probe = NodeProbe::new(self.nodes[index].clone());
+                probe.record_error(err);
+                probe
+            })
+        }))
+        .await;
+
+        ProbeReport::new(probes)
+    }
+
+    async fn probe_at(&self, index: usize, cancel: &CancellationToken) -> NodeProbe {
+        let mut probe = NodeProbe::new(self.nodes[index].clone());
+
+        let started_at = Instant::now();
+        match self.call_on(&Health, index, cancel).await {
+            Ok(()) => {
+                probe.latency = Some(started_at.elapsed());
+                probe.healthy = true;
+            }
+            // The node is unreachable: do not wait for it again.
+            Err(err @ Error::HttpClientError(_)) => {
+                probe.record_error(err);
+                return probe;
+            }
+            Err(err) => probe.record_error(err),
+        }
+
+        match self.discover_at(index, cancel).await {
+            Ok((info, capabilities)) => {
+                probe.record_info(&info);
+                probe.capabilities = Some(capabilities);
+            }
+            Err(err) => probe.record_error(err),
+        }
+
+        probe
+    }
+}
@@ -0,0 +1,113 @@
+//! Promotion and reattachment of blocks for [AsyncClient].
+
+use crate::api::{core::GetBlockMetadata, Uncached};
+use crate::types::{
+    Block, BlockId, Parents, PromotionError, PromotionPolicy, PromotionReport, Reaction, Result,
+};
+use crate::AsyncClient;
+use alloc::boxed::Box;
+use futures_util::future::{self, Either};
+
+impl AsyncClient {
+    /// Issue a block without payload approving `block_id` and fresh tips, to get `block_id`
+    /// referenced sooner. Returns the ID of the promotion block.
+    pub async fn promote(&self, block_id: BlockId) -> Result<BlockId> {
+        let parents = Parents::including(block_id, self.tips().await?.tips)?;
+
+        self.submit_block(Block::new(parents, None)).await
+    }
+
+    /// Attach the payload of `block_id` again, in a new block with fresh parents. Returns the ID
+    /// of the new block.
+    pub async fn reattach(&self, block_id: BlockId) -> Result<BlockId> {
+        let block = self.get_block(block_id).await?;
+
+        self.submit_block(Block::new(self.parents().await?, block.payload))
+            .await
+    }
+
+    /// Watch `block_id` until it or one of its reattachments is referenced, promoting or
+    /// reattaching it as the node advises, within the budgets of `policy`. Pauses between checks
+    /// are taken with the timer of the client.
+    ///
+    /// Running out of budget or time is not an error: the returned report then tells no
+    /// attachment got referenced. Attachments unknown to the node yet and transient errors are
+    /// waited out; other errors are returned with the report of what was done until then.
+    pub async fn promote_until_referenced(
+        &self,
+        block_id: BlockId,
+        policy: &PromotionPolicy,
+    ) -> core::result::Result<PromotionReport, PromotionError> {
+        let mut report = PromotionReport::new(block_id);
+        let timer = match self.timer() {
+            Ok(timer) => timer,
+            Err(error) => {
+                return Err(PromotionError {
+                    error,
+                    report: Box::new(report),
+                })
+            }
+        };
+        let mut deadline = policy.timeout.map(|timeout| timer.sleep(timeout));
+
+        loop {
+            let mut latest = None;
+            for attachment in report.attachments().to_vec() {
+                match self
+                    .call(&Uncached(&GetBlockMetadata {
+                        block_id: attachment,
+                    }))
+                    .await
+                {
+                    Ok(metadata) if metadata.is_referenced() => {
+                        report.referenced = Some(metadata);
+                        return Ok(report);
+                    }
+                    Ok(metadata) => latest = Some(metadata),
+                    // Not spread to the node yet, or the node is unavailable: check again later.
+                    Err(error) if error.may_clear() => latest = None,
+                    Err(error) => {
+                        return Err(PromotionError {
+                            error,
+                            report: Box::new(report),
+                        })
+                    }
+                }
+            }
+
+            if let Some(metadata) = latest {
+                let reacted = match policy.react(&metadata, &report) {
+                    Reaction::Wait => Ok(()),
+                    Reaction::Promote => self
+                        .promote(metadata.block_id)
+                        .await
+                        .map(|promotion| report.promoted(promotion)),
+                    Reaction::Reattach => self
+                        .reattach(metadata.block_id)
+                        .await
+                        .map(|reattachment| report.reattached(reattachment)),
+                    Reaction::GiveUp => return Ok(report),
+                };
+                match reacted {
+                    Err(error) if !error.is_transient() => {
+                        return Err(PromotionError {
+                            error,
+                            report: Box::new(report),
+                        })
+                    }
+                    _ => {}
+                }
+            }
+
+            let pause = timer.sleep(policy.interval);
+            match deadline.as_mut() {
+                Some(deadline) => {
+                    if let Either::Right(_) = future::select(pause, deadline).await {
+                        return Ok(report);
+                    }
+                }
+                None => pause.await,
+            }
+        }
+    }
+}
@@ -0,0 +1,21 @@
+//! Implementation of the Chrysalis REST API (v1) for [AsyncClient], on nodes speaking it.
+
+use crate::api::v1::{Message, MessagesByIndex};
+use crate::types::v1::{Message as MessageResponse, MessageId, MessagesByIndexResponse};
+use crate::types::Result;
+use crate::AsyncClient;
+
+impl AsyncClient {
+    /// A Chrysalis message by its ID.
+    pub async fn message(&self, message_id: MessageId) -> Result<MessageResponse> {
+        self.call(&Message { message_id }).await
+    }
+
+    /// IDs of the Chrysalis messages with an indexation payload of `index`.
+    pub async fn messages_by_index(&self, index: &[u8]) -> Result<MessagesByIndexResponse> {
+        self.call(&MessagesByIndex {
+            index: index.into(),
+        })
+        .await
+    }
+}
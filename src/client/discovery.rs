@@ -0,0 +1,221 @@
+//! Capabilities discovered on nodes, shared by the clients.
+
+use crate::types::{ApiVersion, Capabilities, Capability, Error, NodeUrl, Result};
+use alloc::collections::BTreeMap;
+use alloc::{string::String, vec::Vec};
+use spin::Mutex;
+
+/// How long a failed discovery is remembered before the node is queried again.
+#[cfg(feature = "std")]
+pub(crate) const FAILURE_TTL: core::time::Duration = core::time::Duration::from_secs(10);
+
+/// Capabilities discovered so far, by node URL. Shared between clones of a client, so every node
+/// is only queried once.
+///
+/// Failures are remembered for [`FAILURE_TTL`] with the `std` feature, so unreachable nodes are
+/// not queried again on every call; they are not remembered otherwise.
+#[derive(Debug, Default)]
+pub(crate) struct Discovered(Mutex<BTreeMap<NodeUrl, Discovery>>);
+
+#[derive(Debug)]
+enum Discovery {
+    Found(Capabilities),
+    #[cfg(feature = "std")]
+    Failed {
+        error: Error,
+        expires_at: std::time::Instant,
+    },
+}
+
+impl Discovered {
+    /// The outcome of the last discovery of the node at `url`, unless it needs to be queried.
+    pub(crate) fn get(&self, url: &NodeUrl) -> Option<Result<Capabilities>> {
+        match self.0.lock().get(url)? {
+            Discovery::Found(capabilities) => Some(Ok(capabilities.clone())),
+            #[cfg(feature = "std")]
+            Discovery::Failed { error, expires_at } if *expires_at > std::time::Instant::now() => {
+                Some(Err(error.clone()))
+            }
+            #[cfg(feature = "std")]
+            Discovery::Failed { .. } => None,
+        }
+    }
+
+    /// Remember the outcome of a discovery of the node at `url`. Failures on the side of the
+    /// caller, like a cancellation, are not remembered.
+    pub(crate) fn insert(&self, url: NodeUrl, discovery: Result<Capabilities>) {
+        let discovery = match discovery {
+            Ok(capabilities) => Discovery::Found(capabilities),
+            #[cfg(feature = "std")]
+            Err(
+                error @ (Error::HttpClientError(_)
+                | Error::NodeError { .. }
+                | Error::RateLimited { .. }
+                | Error::ResponseError(_)),
+            ) => Discovery::Failed {
+                error,
+                expires_at: std::time::Instant::now() + FAILURE_TTL,
+            },
+            Err(_) => return,
+        };
+
+        self.0.lock().insert(url, discovery);
+    }
+}
+
+/// Pick up to `wanted` nodes offering `capability` out of the discovery results of the nodes, in
+/// order. The results are evaluated lazily, so nodes after the `wanted` ones are not discovered.
+///
+/// Nodes whose discovery failed are skipped; if no node is left, the last discovery error is
+/// returned, unless all nodes were reachable and simply lack the capability.
+pub(crate) fn offering<I>(
+    capability: Capability,
+    discovered: I,
+    wanted: usize,
+) -> Result<Vec<usize>>
+where
+    I: IntoIterator<Item = Result<Capabilities>>,
+{
+    let mut nodes = Vec::new();
+    let mut error = None;
+
+    for (index, capabilities) in discovered.into_iter().enumerate() {
+        match capabilities {
+            Ok(capabilities) if capabilities.supports(capability) => nodes.push(index),
+            Ok(_) => {}
+            Err(err) => error = Some(err),
+        }
+
+        if nodes.len() >= wanted.max(1) {
+            break;
+        }
+    }
+
+    match (nodes.is_empty(), error) {
+        (false, _) => Ok(nodes),
+        (true, Some(err)) => Err(err),
+        (true, None) => Err(Error::UnsupportedByNode(capability)),
+    }
+}
+
+/// The API generation of the first node whose discovery succeeded, out of the discovery results
+/// of all nodes (evaluated lazily), or the last discovery error.
+pub(crate) fn preferred_api_version<I>(discovered: I) -> Result<ApiVersion>
+where
+    I: IntoIterator<Item = Result<Capabilities>>,
+{
+    let mut error = Error::MissingNode;
+
+    for capabilities in discovered {
+        match capabilities {
+            Ok(capabilities) => return Ok(capabilities.api_version().unwrap_or_default()),
+            Err(err) => error = err,
+        }
+    }
+
+    Err(error)
+}
+
+/// The WebSocket URL of the MQTT event API of the node at `url`.
+pub(crate) fn mqtt_url(url: &NodeUrl) -> String {
+    let url = url.join("/api/mqtt/v1");
+    let (_, rest) = url.split_once("://").unwrap_or(("", &url));
+
+    alloc::format!(
+        "{}://{}",
+        if url.starts_with("https") {
+            "wss"
+        } else {
+            "ws"
+        },
+        rest
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn offering_skips_failed_and_lacking_nodes() {
+        let mut indexer = Capabilities::new();
+        indexer.add_route("indexer/v1");
+
+        let discovered = alloc::vec![
+            Err(Error::MissingNode),
+            Ok(Capabilities::new()),
+            Ok(indexer),
+        ];
+        assert_eq!(
+            offering(Capability::Indexer, discovered, 2),
+            Ok(alloc::vec![2])
+        );
+
+        let discovered = alloc::vec![Ok(Capabilities::new()), Ok(Capabilities::new())];
+        assert_eq!(
+            offering(Capability::Mqtt, discovered, 2),
+            Err(Error::UnsupportedByNode(Capability::Mqtt))
+        );
+
+        let discovered = alloc::vec![Ok(Capabilities::new()), Err(Error::MissingNode)];
+        assert_eq!(
+            offering(Capability::Mqtt, discovered, 2),
+            Err(Error::MissingNode)
+        );
+    }
+
+    #[test]
+    fn offering_stops_once_enough_nodes_are_found() {
+        let mut v2 = Capabilities::new();
+        v2.add_route("core/v2");
+        let mut evaluated = 0;
+        let discovered = (0..5).map(|_| {
+            evaluated += 1;
+            Ok(v2.clone())
+        });
+
+        assert_eq!(
+            offering(ApiVersion::V2.into(), discovered, 2),
+            Ok(alloc::vec![0, 1])
+        );
+        assert_eq!(evaluated, 2);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn discovered_failures_expire() {
+        let url: NodeUrl = "https://node.example.iota.org".parse().unwrap();
+        let discovered = Discovered::default();
+
+        discovered.insert(url.clone(), Err(Error::Cancelled));
+        assert_eq!(discovered.get(&url), None);
+
+        let error = Error::HttpClientError("connection refused".into());
+        discovered.insert(url.clone(), Err(error.clone()));
+        assert_eq!(discovered.get(&url), Some(Err(error.clone())));
+
+        discovered.0.lock().insert(
+            url.clone(),
+            Discovery::Failed {
+                error,
+                expires_at: std::time::Instant::now(),
+            },
+        );
+        assert_eq!(discovered.get(&url), None);
+
+        discovered.insert(url.clone(), Ok(Capabilities::new()));
+        assert_eq!(discovered.get(&url), Some(Ok(Capabilities::new())));
+    }
+
+    #[test]
+    fn mqtt_url_uses_websockets() {
+        let https: NodeUrl = "https://node.example.iota.org/base".parse().unwrap();
+        assert_eq!(
+            mqtt_url(&https),
+            "wss://node.example.iota.org/base/api/mqtt/v1"
+        );
+
+        let http: NodeUrl = "http://localhost:14265".parse().unwrap();
+        assert_eq!(mqtt_url(&http), "ws://localhost:14265/api/mqtt/v1");
+    }
+}
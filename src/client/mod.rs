@@ -6,15 +6,47 @@
 //! client automatically retries on another node, until getting a successful response or having
 //! tried on all nodes and failed.
 //!
+//! Clients are cheap to clone, and clones share their HTTP client, cache, rate limits and the
+//! capabilities discovered on nodes. A single client can thus serve a whole application, across
+//! threads and tasks.
+//!
 //! # Examples
+//!
+//! ```
+//! use atoi::comm::http::DummyHttpClient;
+//! use atoi::types::Node;
+//! use atoi::Client;
+//! use std::sync::Arc;
+//!
+//! let client = Client::builder()
+//!     .nodes(vec!["https://example.iota.org".parse::<Node>().unwrap()])
+//!     .http(Arc::new(DummyHttpClient::new()))
+//!     .build();
+//!
+//! let workers: Vec<_> = (0..4)
+//!     .map(|_| {
+//!         let client = client.clone();
+//!         std::thread::spawn(move || client.info())
+//!     })
+//!     .collect();
+//!
+//! for worker in workers {
+//!     assert!(worker.join().unwrap().is_ok());
+//! }
+//! ```
 
 #[cfg(feature = "async")]
 mod asynch;
+#[cfg(any(feature = "sync", feature = "async"))]
+mod discovery;
 #[cfg(feature = "sync")]
 mod synch;
 
 // Exports.
 #[cfg(feature = "async")]
-pub use self::asynch::AsyncClient;
+pub use self::asynch::{AsyncClient, CallOptions};
 #[cfg(feature = "sync")]
 pub use self::synch::Client;
+
+/// The default maximum number of requests in flight during batch calls.
+pub const DEFAULT_CONCURRENCY: usize = 8;
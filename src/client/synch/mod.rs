@@ -3,21 +3,120 @@
 mod private;
 mod public;
 
-use crate::comm::http::HttpClient;
-use crate::types::Node;
-use alloc::boxed::Box;
+#[cfg(feature = "std")]
+use crate::api::RateLimiter;
+use crate::cache::{Cache, LruCache};
+use crate::client::discovery::Discovered;
+#[cfg(feature = "curl")]
+use crate::comm::http::CurlHttpClient;
+use crate::comm::http::{DummyHttpClient, HttpClient};
+#[cfg(feature = "metrics")]
+use crate::metrics::Recorder;
+use crate::types::{Backend, Config, Node, Quorum, Result};
+use alloc::{sync::Arc, vec::Vec};
 use typed_builder::TypedBuilder;
 
 /// The entry point of IOTA.
-#[derive(Default, TypedBuilder)]
+#[derive(Clone, Default, TypedBuilder)]
 #[builder(field_defaults(default, setter(strip_option)))]
 pub struct Client {
-    pub node: Option<Node>,
-    pub http: Option<Box<dyn HttpClient>>,
+    /// Nodes to connect to, in the order of preference.
+    #[builder(setter(!strip_option, into))]
+    pub nodes: Vec<Node>,
+    pub http: Option<Arc<dyn HttpClient>>,
+    /// How many times a failed request is retried. Each retry is sent to the next node.
+    #[builder(setter(!strip_option))]
+    pub retries: usize,
+    /// Quorum settings for requests querying the ledger.
+    pub quorum: Option<Quorum>,
+    /// Limits of the rate of requests to each node. Nodes responding `429 Too Many Requests` are
+    /// paused even without limits.
+    #[cfg(feature = "std")]
+    #[builder(setter(!strip_option, into))]
+    pub rate_limiter: Arc<RateLimiter>,
+    /// Cache of responses. Without one, every call is sent to a node.
+    pub cache: Option<Arc<dyn Cache>>,
+    /// Receiver of metrics about requests.
+    #[cfg(feature = "metrics")]
+    pub metrics: Option<Arc<dyn Recorder>>,
+    /// Maximum number of worker threads during batch calls like [`call_many`], defaulting to
+    /// [`DEFAULT_CONCURRENCY`](crate::client::DEFAULT_CONCURRENCY).
+    ///
+    /// [`call_many`]: Self::call_many
+    pub concurrency: Option<usize>,
+    /// Capabilities discovered on nodes so far.
+    #[builder(default, setter(skip))]
+    discovered: Arc<Discovered>,
 }
 
 impl Client {
     pub fn new() -> Self {
         Default::default()
     }
+
+    /// Construct a client from a [`Config`], overridden by environment variables with the `std`
+    /// feature (see [`Config::apply_env`]).
+    pub fn from_config(config: Config) -> Result<Self> {
+        #[cfg(feature = "std")]
+        let config = {
+            let mut config = config;
+            config.apply_env()?;
+            config
+        };
+
+        let http: Option<Arc<dyn HttpClient>> = match config.backend {
+            Some(Backend::Dummy) => Some(Arc::new(DummyHttpClient::new())),
+            #[cfg(feature = "curl")]
+            Some(Backend::Curl) => {
+                let mut curl = CurlHttpClient::new();
+                curl.timeout = config.timeout();
+                Some(Arc::new(curl))
+            }
+            #[cfg(not(feature = "curl"))]
+            Some(Backend::Curl) => {
+                return Err(crate::types::Error::ConfigError(
+                    "the `curl` backend is not enabled in this build".into(),
+                ))
+            }
+            None => None,
+        };
+
+        Ok(Self {
+            nodes: config.resolved_nodes(),
+            http,
+            retries: config.retries,
+            quorum: config.quorum,
+            #[cfg(feature = "std")]
+            rate_limiter: Arc::new(RateLimiter::new(config.rate_limit)),
+            cache: match config.cache_capacity {
+                Some(0) | None => None,
+                Some(capacity) => Some(Arc::new(LruCache::new(capacity))),
+            },
+            #[cfg(feature = "metrics")]
+            metrics: None,
+            concurrency: config.concurrency,
+            discovered: Default::default(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Client;
+    use crate::types::Config;
+
+    #[test]
+    fn caches_only_when_configured() {
+        assert!(Client::new().cache.is_none());
+        assert!(Client::from_config(Config::default())
+            .unwrap()
+            .cache
+            .is_none());
+
+        let config = Config {
+            cache_capacity: Some(16),
+            ..Default::default()
+        };
+        assert!(Client::from_config(config).unwrap().cache.is_some());
+    }
 }
@@ -0,0 +1,146 @@
+//! Discovery of the capabilities of nodes.
+
+use crate::api::core::{Info, Routes};
+use crate::api::v1;
+use crate::client::discovery;
+use crate::types::{ApiVersion, Capabilities, Capability, Error, NodeInfo, Result};
+use crate::Client;
+use alloc::vec::Vec;
+
+impl Client {
+    /// The capabilities of the node at `index`, queried once and remembered afterwards. Failures
+    /// are remembered for a short while.
+    pub(crate) fn capabilities_at(&self, index: usize) -> Result<Capabilities> {
+        let url = &self.nodes[index].url;
+
+        if let Some(discovery) = self.discovered.get(url) {
+            return discovery;
+        }
+
+        self.discover_at(index)
+            .map(|(_, capabilities)| capabilities)
+    }
+
+    /// Query the info and capabilities of the node at `index`, and remember the capabilities.
+    pub(crate) fn discover_at(&self, index: usize) -> Result<(NodeInfo, Capabilities)> {
+        // Ask the node itself, as responses differ between nodes. Chrysalis nodes only answer
+        // the v1 info, but unreachable nodes are not asked twice.
+        let discovery = match self.call_on(&Info, index) {
+            Ok(info) => {
+                let mut capabilities = Capabilities::from(&info);
+                // Older nodes have no `/api/routes`; their plugins are listed in the info.
+                if let Ok(routes) = self.call_on(&Routes, index) {
+                    routes
+                        .routes
+                        .iter()
+                        .for_each(|route| capabilities.add_route(route));
+                }
+                Ok((NodeInfo::Stardust(info), capabilities))
+            }
+            Err(err @ Error::HttpClientError(_)) => Err(err),
+            Err(err) => match self.call_on(&v1::Info, index) {
+                Ok(info) => {
+                    let capabilities = Capabilities::from(&info);
+                    Ok((NodeInfo::Chrysalis(info), capabilities))
+                }
+                Err(_) => Err(err),
+            },
+        };
+
+        self.discovered.insert(
+            self.nodes[index].url.clone(),
+            discovery.clone().map(|(_, capabilities)| capabilities),
+        );
+
+        discovery
+    }
+
+    /// The newest API generation spoken by the preferred reachable node.
+    pub(crate) fn preferred_api_version(&self) -> Result<ApiVersion> {
+        discovery::preferred_api_version(
+            (0..self.nodes.len()).map(|index| self.capabilities_at(index)),
+        )
+    }
+
+    /// Indices of up to `wanted` nodes offering `capability`, in the order of preference. Nodes
+    /// are discovered one after another, until enough of them are found.
+    pub(crate) fn nodes_offering(
+        &self,
+        capability: Capability,
+        wanted: usize,
+    ) -> Result<Vec<usize>> {
+        discovery::offering(
+            capability,
+            (0..self.nodes.len()).map(|index| self.capabilities_at(index)),
+            wanted,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::Method;
+    use crate::comm::http::fake::FakeHttpClient;
+    use crate::types::{Error, Node};
+    use crate::Client;
+    use alloc::sync::Arc;
+    use alloc::vec;
+
+    #[test]
+    fn unreachable_nodes_are_not_queried_on_every_call() {
+        const DOWN: &str = "https://down.example.iota.org";
+        let http = Arc::new(FakeHttpClient::new());
+        http.fail(
+            Method::Get,
+            "https://down.example.iota.org/api/v2/info",
+            Error::HttpClientError("connection refused".into()),
+        )
+        .node("https://up.example.iota.org", &["core/v2"])
+        .respond(
+            Method::Get,
+            "https://up.example.iota.org/api/v2/tips",
+            200,
+            r#"{"tips":[]}"#,
+        );
+        let client = Client::builder()
+            .nodes(vec![
+                DOWN.parse::<Node>().unwrap(),
+                "https://up.example.iota.org".parse::<Node>().unwrap(),
+            ])
+            .http(http.clone())
+            .build();
+
+        for _ in 0..3 {
+            assert!(client.tips().is_ok());
+        }
+
+        assert_eq!(http.count("https://down.example.iota.org/api/v2/info"), 1);
+        assert_eq!(
+            client.capabilities()[0],
+            Err(Error::HttpClientError("connection refused".into()))
+        );
+    }
+
+    #[test]
+    fn later_nodes_are_discovered_only_when_needed() {
+        let http = Arc::new(FakeHttpClient::new());
+        http.node("https://up.example.iota.org", &["core/v2"])
+            .respond(
+                Method::Get,
+                "https://up.example.iota.org/api/v2/tips",
+                200,
+                r#"{"tips":[]}"#,
+            );
+        let client = Client::builder()
+            .nodes(vec![
+                "https://up.example.iota.org".parse::<Node>().unwrap(),
+                "https://down.example.iota.org".parse::<Node>().unwrap(),
+            ])
+            .http(http.clone())
+            .retries(0)
+            .build();
+
+        assert!(client.tips().is_ok());
+        assert_eq!(http.count("https://down.example.iota.org/api/v2/info"), 0);
+    }
+}
@@ -1,23 +1,294 @@
-//! High-level HTTP methods sending request to selected nodes.
+//! Driver performing the HTTP requests of [API calls][Call] on selected nodes.
+//!
+//! [Call]: crate::api::Call
 
-use crate::comm::http::HttpClient;
-use crate::types::{Error, Node, Result};
+use crate::api::{Call, Endpoint, Method, Step};
+use crate::types::{Error, Quorum, Result};
 use crate::Client;
 use alloc::vec::Vec;
 
 impl Client {
-    fn http_and_node(&self) -> Result<(&dyn HttpClient, &Node)> {
-        match (&self.http, &self.node) {
-            (Some(http), Some(node)) => Ok((http.as_ref(), node)),
-            (Some(_), None) => Err(Error::MissingNode),
-            (None, Some(_)) | (None, None) => Err(Error::MissingHttpClient),
+    /// Call an API endpoint on the configured nodes.
+    pub fn call<E: Endpoint>(&self, endpoint: &E) -> Result<E::Output> {
+        self.call_at(endpoint, 0)
+    }
+
+    /// Call many API endpoints on the configured nodes, on at most
+    /// [`concurrency`](Self::concurrency) worker threads (or one after another without the `std`
+    /// feature).
+    ///
+    /// Calls are spread over the nodes: the `n`-th call starts with the `n`-th node. Results are
+    /// returned in the order of `endpoints`.
+    pub fn call_many<E, I>(&self, endpoints: I) -> Vec<Result<E::Output>>
+    where
+        E: Endpoint + Send + 'static,
+        E::Output: Send + 'static,
+        I: IntoIterator<Item = E>,
+    {
+        #[cfg(feature = "std")]
+        return self.call_many_threaded(endpoints);
+
+        #[cfg(not(feature = "std"))]
+        return endpoints
+            .into_iter()
+            .enumerate()
+            .map(|(index, endpoint)| self.call_at(&endpoint, index))
+            .collect();
+    }
+
+    #[cfg(feature = "std")]
+    fn call_many_threaded<E, I>(&self, endpoints: I) -> Vec<Result<E::Output>>
+    where
+        E: Endpoint + Send + 'static,
+        E::Output: Send + 'static,
+        I: IntoIterator<Item = E>,
+    {
+        use crate::client::DEFAULT_CONCURRENCY;
+        use std::sync::{Arc, Mutex};
+
+        let jobs: Vec<(usize, E)> = endpoints.into_iter().enumerate().collect();
+        let mut results: Vec<Option<Result<E::Output>>> = (0..jobs.len()).map(|_| None).collect();
+        let workers = self
+            .concurrency
+            .unwrap_or(DEFAULT_CONCURRENCY)
+            .clamp(1, jobs.len().max(1));
+        let queue = Arc::new(Mutex::new(jobs.into_iter()));
+
+        let workers: Vec<_> = (0..workers)
+            .map(|_| {
+                let client = self.clone();
+                let queue = queue.clone();
+
+                std::thread::spawn(move || {
+                    let mut results = Vec::new();
+
+                    loop {
+                        let job = queue.lock().unwrap_or_else(|err| err.into_inner()).next();
+
+                        match job {
+                            Some((index, endpoint)) => {
+                                results.push((index, client.call_at(&endpoint, index)))
+                            }
+                            None => return results,
+                        }
+                    }
+                })
+            })
+            .collect();
+
+        for worker in workers {
+            match worker.join() {
+                Ok(finished) => finished
+                    .into_iter()
+                    .for_each(|(index, result)| results[index] = Some(result)),
+                Err(panic) => std::panic::resume_unwind(panic),
+            }
+        }
+
+        results.into_iter().flatten().collect()
+    }
+
+    fn call_at<E: Endpoint>(&self, endpoint: &E, first_node: usize) -> Result<E::Output> {
+        let call = self
+            .prepare(endpoint, self.retries, self.quorum)?
+            .starting_at(first_node)
+            .with_cache(self.cache.as_deref());
+        let call = match endpoint.capability() {
+            Some(capability) => {
+                let nodes = self.nodes_offering(capability, call.nodes_needed())?;
+                call.restricted_to(nodes)
+            }
+            None => call,
+        };
+
+        self.run(call)
+    }
+
+    /// Call an API endpoint on the node at `index` only, without retries nor the cache shared by
+    /// all nodes.
+    pub(crate) fn call_on<E: Endpoint>(&self, endpoint: &E, index: usize) -> Result<E::Output> {
+        if index >= self.nodes.len() {
+            return Err(Error::MissingNode);
+        }
+
+        self.run(
+            self.prepare(endpoint, 0, None)?
+                .restricted_to(alloc::vec![index]),
+        )
+    }
+
+    /// Start a call on the configured nodes, with the rate limits and metrics of the client.
+    fn prepare<'a, E: Endpoint>(
+        &'a self,
+        endpoint: &'a E,
+        retries: usize,
+        quorum: Option<Quorum>,
+    ) -> Result<Call<'a, E>> {
+        let call = Call::new(endpoint, &self.nodes, retries, quorum)?;
+        #[cfg(feature = "std")]
+        let call = call.with_rate_limiter(Some(&self.rate_limiter));
+        #[cfg(feature = "metrics")]
+        let call = call.with_metrics(self.metrics.as_deref());
+
+        Ok(call)
+    }
+
+    /// Drive `call` to its end.
+    fn run<E: Endpoint>(&self, mut call: Call<'_, E>) -> Result<E::Output> {
+        let http = self.http.as_deref().ok_or(Error::MissingHttpClient)?;
+
+        loop {
+            match call.step() {
+                Step::Transmit(transmit) => {
+                    #[cfg(feature = "tracing")]
+                    let _span = call.attempt_span().clone().entered();
+
+                    #[cfg(feature = "std")]
+                    if !transmit.delay.is_zero() {
+                        std::thread::sleep(transmit.delay);
+                    }
+
+                    call.receive(match transmit.method {
+                        Method::Get => http.get(&transmit.url, transmit.auth, transmit.accept),
+                        Method::Post => http.post(
+                            &transmit.url,
+                            transmit.auth,
+                            &transmit.body,
+                            transmit.content_type,
+                        ),
+                        Method::Delete => http.delete(&transmit.url, transmit.auth),
+                    });
+                }
+                Step::Done(result) => return result,
+            }
         }
     }
+}
+
+#[cfg(all(test, feature = "tracing"))]
+mod tests {
+    use crate::api::Method;
+    use crate::comm::http::fake::FakeHttpClient;
+    use crate::types::Node;
+    use crate::Client;
+    use alloc::string::String;
+    use alloc::sync::Arc;
+    use alloc::vec;
+    use spin::Mutex;
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+
+    /// A subscriber remembering spans, and the messages of events with the names of their
+    /// enclosing spans, innermost first.
+    #[derive(Clone, Default)]
+    struct Recording(Arc<Recorded>);
+
+    #[derive(Default)]
+    struct Recorded {
+        /// Names and parents of the spans, by ID minus one.
+        spans: Mutex<Vec<(&'static str, Option<u64>)>>,
+        entered: Mutex<Vec<u64>>,
+        events: Mutex<Vec<(String, Vec<&'static str>)>>,
+    }
+
+    impl Recording {
+        fn parent(&self, explicit: Option<&Id>, contextual: bool) -> Option<u64> {
+            match (explicit, contextual) {
+                (Some(parent), _) => Some(parent.into_u64()),
+                (None, true) => self.0.entered.lock().last().copied(),
+                (None, false) => None,
+            }
+        }
+    }
+
+    impl Subscriber for Recording {
+        fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, attributes: &Attributes<'_>) -> Id {
+            let parent = self.parent(attributes.parent(), attributes.is_contextual());
+            let mut spans = self.0.spans.lock();
+            spans.push((attributes.metadata().name(), parent));
+
+            Id::from_u64(spans.len() as u64)
+        }
+
+        fn record(&self, _span: &Id, _values: &Record<'_>) {}
+
+        fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            struct Message(String);
+
+            impl Visit for Message {
+                fn record_debug(&mut self, field: &Field, value: &dyn core::fmt::Debug) {
+                    if field.name() == "message" {
+                        self.0 = alloc::format!("{:?}", value);
+                    }
+                }
+            }
+
+            let mut message = Message(String::new());
+            event.record(&mut message);
+
+            let spans = self.0.spans.lock();
+            let mut names = Vec::new();
+            let mut parent = self.parent(event.parent(), event.is_contextual());
+            while let Some(id) = parent {
+                let (name, grandparent) = spans[id as usize - 1];
+                names.push(name);
+                parent = grandparent;
+            }
+
+            self.0.events.lock().push((message.0, names));
+        }
+
+        fn enter(&self, span: &Id) {
+            self.0.entered.lock().push(span.into_u64());
+        }
+
+        fn exit(&self, _span: &Id) {
+            self.0.entered.lock().pop();
+        }
+    }
+
+    #[test]
+    fn http_client_events_belong_to_the_request_and_the_call() {
+        const NODE: &str = "https://node.example.iota.org";
+        let http = Arc::new(FakeHttpClient::new());
+        http.node(NODE, &["core/v2"])
+            .respond(
+                Method::Get,
+                &alloc::format!("{}/api/v2/tips", NODE),
+                503,
+                "",
+            )
+            .respond(
+                Method::Get,
+                &alloc::format!("{}/api/v2/tips", NODE),
+                200,
+                r#"{"tips":[]}"#,
+            );
+        let client = Client::builder()
+            .nodes(vec![NODE.parse::<Node>().unwrap()])
+            .http(http)
+            .retries(1)
+            .build();
+        let recording = Recording::default();
 
-    pub(crate) fn http_get(&self, path: &str) -> Result<Vec<u8>> {
-        let (http, node) = self.http_and_node()?;
-        let url = node.url.clone() + path;
+        tracing::subscriber::with_default(recording.clone(), || client.tips().unwrap());
 
-        http.get(&url, node.auth.as_ref())
+        let events = recording.0.events.lock();
+        let answered: Vec<_> = events
+            .iter()
+            .filter(|(message, _)| message == "fake request answered")
+            .collect();
+        // Discovery of the node, then the failed and the retried request.
+        assert_eq!(answered.len(), 4);
+        assert!(answered
+            .iter()
+            .all(|(_, spans)| spans.as_slice() == ["request", "call"]));
     }
 }
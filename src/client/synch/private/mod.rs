@@ -2,4 +2,5 @@
 //!
 //! [Client]: super::Client
 
+mod discovery;
 mod http;
@@ -0,0 +1,201 @@
+//! Waiting for confirmations with [Client].
+
+use crate::api::core::{GetBlockMetadata, GetIncludedBlockMetadata};
+use crate::api::{Endpoint, Uncached};
+use crate::types::{
+    BlockId, BlockMetadata, Confirmation, Error, Result, TransactionId, WaitOptions,
+};
+use crate::Client;
+use std::time::Instant;
+
+impl Client {
+    /// Wait until a milestone references `block_id`, polling its metadata as told by `options`.
+    /// The block may be unknown to the node at first, and failures of the node are waited out.
+    pub fn wait_for_block_confirmation(
+        &self,
+        block_id: BlockId,
+        options: &WaitOptions,
+    ) -> Result<Confirmation> {
+        self.poll_until_confirmed(&GetBlockMetadata { block_id }, options)
+    }
+
+    /// Wait until a milestone references a block including `transaction_id`, polling the metadata
+    /// of that block as told by `options`. The transaction may be unknown to the node at first,
+    /// and failures of the node are waited out.
+    pub fn wait_for_transaction_confirmation(
+        &self,
+        transaction_id: TransactionId,
+        options: &WaitOptions,
+    ) -> Result<Confirmation> {
+        self.poll_until_confirmed(&GetIncludedBlockMetadata { transaction_id }, options)
+    }
+
+    fn poll_until_confirmed<E>(&self, endpoint: &E, options: &WaitOptions) -> Result<Confirmation>
+    where
+        E: Endpoint<Output = BlockMetadata>,
+    {
+        let started_at = Instant::now();
+
+        loop {
+            match self.call(&Uncached(endpoint)) {
+                Ok(metadata) => {
+                    if let Some(confirmation) = Confirmation::from_metadata(&metadata) {
+                        return Ok(confirmation);
+                    }
+                }
+                // Not spread to the node yet, or the node is unavailable: poll again later.
+                Err(err) if err.may_clear() => {}
+                Err(err) => return Err(err),
+            }
+
+            match options.timeout.checked_sub(started_at.elapsed()) {
+                Some(left) if !left.is_zero() => std::thread::sleep(options.interval.min(left)),
+                _ => return Err(Error::Timeout),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::Method;
+    use crate::comm::http::fake::FakeHttpClient;
+    use crate::types::{BlockId, Error, Node, TransactionId, WaitOptions};
+    use crate::Client;
+    use alloc::sync::Arc;
+    use alloc::vec;
+    use core::time::Duration;
+
+    const NODE: &str = "https://node.example.iota.org";
+    const BLOCK_ID: &str = "0x9a1a0b1f81a4c25d9d6d0e8c4e6a2d8d4e0f5c2b1a3e7d6c5b4a39281706f5e4";
+
+    fn client(http: &Arc<FakeHttpClient>) -> Client {
+        http.node(NODE, &["core/v2"]);
+        Client::builder()
+            .nodes(vec![NODE.parse::<Node>().unwrap()])
+            .http(http.clone())
+            .retries(0)
+            .build()
+    }
+
+    fn options(timeout_ms: u64) -> WaitOptions {
+        WaitOptions::builder()
+            .interval(Duration::from_millis(1))
+            .timeout(Duration::from_millis(timeout_ms))
+            .build()
+    }
+
+    fn metadata(referenced: &str) -> alloc::string::String {
+        alloc::format!(
+            r#"{{"blockId":"{}","parents":[],"isSolid":true{}}}"#,
+            BLOCK_ID,
+            referenced
+        )
+    }
+
+    #[test]
+    fn waits_until_referenced() {
+        let http = Arc::new(FakeHttpClient::new());
+        let url = alloc::format!("{}/api/v2/blocks/{}/metadata", NODE, BLOCK_ID);
+        http.respond(Method::Get, &url, 200, &metadata(""))
+            .respond(Method::Get, &url, 200, &metadata(""))
+            .respond(
+                Method::Get,
+                &url,
+                200,
+                &metadata(r#","referencedByMilestoneIndex":471"#),
+            );
+        let client = client(&http);
+        let block_id: BlockId = BLOCK_ID.parse().unwrap();
+
+        let confirmation = client
+            .wait_for_block_confirmation(block_id, &options(10_000))
+            .unwrap();
+
+        assert_eq!(confirmation.milestone_index, 471);
+        assert_eq!(confirmation.ledger_inclusion_state, None);
+        assert_eq!(http.count(&url), 3);
+    }
+
+    #[test]
+    fn times_out_while_the_transaction_is_unknown() {
+        let http = Arc::new(FakeHttpClient::new());
+        let client = client(&http);
+        let transaction_id = TransactionId([7; 32]);
+
+        assert_eq!(
+            client.wait_for_transaction_confirmation(transaction_id, &options(20)),
+            Err(Error::Timeout)
+        );
+    }
+
+    #[test]
+    fn waits_out_unknown_blocks_and_node_failures() {
+        let http = Arc::new(FakeHttpClient::new());
+        let url = alloc::format!("{}/api/v2/blocks/{}/metadata", NODE, BLOCK_ID);
+        http.respond(Method::Get, &url, 404, "")
+            .fail(
+                Method::Get,
+                &url,
+                Error::HttpClientError("connection reset".into()),
+            )
+            .respond(Method::Get, &url, 503, "")
+            .respond(
+                Method::Get,
+                &url,
+                200,
+                &metadata(r#","referencedByMilestoneIndex":471"#),
+            );
+        let client = client(&http);
+
+        let confirmation = client
+            .wait_for_block_confirmation(BLOCK_ID.parse().unwrap(), &options(10_000))
+            .unwrap();
+
+        assert_eq!(confirmation.milestone_index, 471);
+        assert_eq!(http.count(&url), 4);
+    }
+
+    #[test]
+    fn stops_at_other_errors() {
+        let http = Arc::new(FakeHttpClient::new());
+        let url = alloc::format!("{}/api/v2/blocks/{}/metadata", NODE, BLOCK_ID);
+        http.respond(Method::Get, &url, 403, "");
+        let client = client(&http);
+
+        assert!(matches!(
+            client.wait_for_block_confirmation(BLOCK_ID.parse().unwrap(), &options(10_000)),
+            Err(Error::NodeError { code: 403, .. })
+        ));
+        assert_eq!(http.count(&url), 1);
+    }
+
+    #[test]
+    fn polls_past_the_cache() {
+        let http = Arc::new(FakeHttpClient::new());
+        let url = alloc::format!("{}/api/v2/blocks/{}/metadata", NODE, BLOCK_ID);
+        http.respond(Method::Get, &url, 200, &metadata("")).respond(
+            Method::Get,
+            &url,
+            200,
+            &metadata(r#","referencedByMilestoneIndex":471"#),
+        );
+        let client = Client {
+            cache: Some(Arc::new(crate::cache::LruCache::new(16))),
+            ..client(&http)
+        };
+        let block_id: BlockId = BLOCK_ID.parse().unwrap();
+
+        // Metadata is cached shortly for other callers.
+        client.get_block_metadata(block_id).unwrap();
+        client.get_block_metadata(block_id).unwrap();
+        assert_eq!(http.count(&url), 1);
+
+        let confirmation = client
+            .wait_for_block_confirmation(block_id, &options(10_000))
+            .unwrap();
+
+        assert_eq!(confirmation.milestone_index, 471);
+        assert_eq!(http.count(&url), 2);
+    }
+}
@@ -0,0 +1,40 @@
+//! Implementation of the node control routes of Hornet for [Client].
+
+use crate::api::control::{CreateSnapshot, PruneDatabase};
+use crate::types::{CreateSnapshotResponse, PruneDatabaseResponse, PruneTarget, Result};
+use crate::Client;
+
+impl Client {
+    /// Prune the database of the node at `node`, an index into [`nodes`](Self::nodes), up to
+    /// `target`.
+    ///
+    /// ```
+    /// use atoi::comm::http::DummyHttpClient;
+    /// use atoi::types::{Auth, Node, PruneTarget};
+    /// use atoi::Client;
+    /// use std::sync::Arc;
+    ///
+    /// let mut node: Node = "https://example.iota.org".parse().unwrap();
+    /// node.auth = Some(Auth::Jwt("eyJhbGciOiJIUzI1NiJ9".into()));
+    /// let client = Client::builder()
+    ///     .nodes(vec![node])
+    ///     .http(Arc::new(DummyHttpClient::new()))
+    ///     .build();
+    ///
+    /// assert_eq!(client.prune_database(0, PruneTarget::Depth(100)).unwrap().index, 380);
+    /// let snapshot = client.create_snapshot(0, 470).unwrap();
+    /// assert_eq!(snapshot.file_path, "snapshots/full_snapshot_470.bin");
+    /// ```
+    pub fn prune_database(
+        &self,
+        node: usize,
+        target: PruneTarget,
+    ) -> Result<PruneDatabaseResponse> {
+        self.call_on(&PruneDatabase { target }, node)
+    }
+
+    /// Make the node at `node` create a snapshot of the ledger at milestone `index`.
+    pub fn create_snapshot(&self, node: usize, index: u32) -> Result<CreateSnapshotResponse> {
+        self.call_on(&CreateSnapshot { index }, node)
+    }
+}
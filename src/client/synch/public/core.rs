@@ -2,16 +2,324 @@
 //!
 //! [core]: https://github.com/iotaledger/tips/pull/57
 
-use crate::types::{InfoResponse, Result};
+use crate::api::core::{
+    AddPeer, GetBlock, GetBlockMetadata, GetBlockRaw, GetIncludedBlock, GetIncludedBlockMetadata,
+    GetIncludedBlockRaw, GetMilestone, GetMilestoneRaw, GetOutput, GetOutputMetadata, GetPeer,
+    GetUtxoChanges, Health, Info, Peers, Receipts, ReceiptsMigratedAt, RemovePeer, Routes,
+    SubmitBlock, Tips, Treasury,
+};
+use crate::api::v1;
+use crate::types::{
+    ApiVersion, Block, BlockId, BlockMetadata, Capabilities, Error, MilestonePayload, MilestoneRef,
+    NodeErrorKind, NodeInfo, OutputId, OutputMetadata, OutputResponse, Parents, Peer,
+    ReceiptsResponse, Result, RoutesResponse, TipsResponse, TransactionId, TransactionStatus,
+    TreasuryResponse, UtxoChangesResponse,
+};
 use crate::Client;
+use alloc::vec::Vec;
 
 impl Client {
     pub fn health(&self) -> Result<()> {
-        self.http_get("/health")?;
-        Ok(())
+        self.call(&Health)
     }
 
-    pub fn info(&self) -> Result<InfoResponse> {
-        Ok(serde_json::from_slice(&self.http_get("/api/v2/info")?)?)
+    /// General information about the preferred node, from the APIs of the generation it speaks.
+    /// The info is only asked of nodes of that generation, the preferred one first.
+    pub fn info(&self) -> Result<NodeInfo> {
+        match self.api_version()? {
+            ApiVersion::V1 => self.call(&v1::Info).map(NodeInfo::Chrysalis),
+            ApiVersion::V2 => self.call(&Info).map(NodeInfo::Stardust),
+        }
+    }
+
+    pub fn routes(&self) -> Result<RoutesResponse> {
+        self.call(&Routes)
+    }
+
+    /// The API generation spoken by the preferred node that can be reached.
+    pub fn api_version(&self) -> Result<ApiVersion> {
+        self.preferred_api_version()
+    }
+
+    /// The capabilities of every configured node, in order. Nodes are only queried the first time.
+    pub fn capabilities(&self) -> Vec<Result<Capabilities>> {
+        (0..self.nodes.len())
+            .map(|index| self.capabilities_at(index))
+            .collect()
+    }
+
+    pub fn tips(&self) -> Result<TipsResponse> {
+        self.call(&Tips)
+    }
+
+    /// Select parents for a new block among the current tips.
+    pub fn parents(&self) -> Result<Parents> {
+        Parents::new(self.tips()?.tips)
+    }
+
+    /// Keep `parents` if selected at most [`Parents::MAX_AGE`] ago, or else select new ones.
+    pub fn refresh_parents(&self, parents: Parents) -> Result<Parents> {
+        if parents.is_stale(Parents::MAX_AGE) {
+            self.parents()
+        } else {
+            Ok(parents)
+        }
+    }
+
+    /// Submit a block, either a [`Block`](crate::types::Block) sent as JSON or bytes of a block
+    /// serialized with the binary serializer, and return its ID.
+    ///
+    /// If the node rejects the block, its reason is returned in
+    /// [`Error::NodeError`](crate::types::Error::NodeError).
+    pub fn submit_block<B: Into<SubmitBlock>>(&self, block: B) -> Result<BlockId> {
+        let block = block.into();
+
+        if let SubmitBlock::Json(block) = &block {
+            block.validate()?;
+        }
+
+        self.call(&block)
+    }
+
+    pub fn get_block(&self, block_id: BlockId) -> Result<Block> {
+        self.call(&GetBlock { block_id })
+    }
+
+    /// A block serialized with the binary serializer.
+    pub fn get_block_raw(&self, block_id: BlockId) -> Result<Vec<u8>> {
+        self.call(&GetBlockRaw { block_id })
+    }
+
+    pub fn get_block_metadata(&self, block_id: BlockId) -> Result<BlockMetadata> {
+        self.call(&GetBlockMetadata { block_id })
+    }
+
+    pub fn get_output(&self, output_id: OutputId) -> Result<OutputResponse> {
+        self.call(&GetOutput { output_id })
+    }
+
+    pub fn get_output_metadata(&self, output_id: OutputId) -> Result<OutputMetadata> {
+        self.call(&GetOutputMetadata { output_id })
+    }
+
+    /// The metadata of many outputs, e.g. to check whether they are
+    /// [spent](OutputMetadata::is_spent), fetched concurrently. Results are returned in the order
+    /// of `output_ids`.
+    pub fn get_outputs_metadata<I>(&self, output_ids: I) -> Vec<Result<OutputMetadata>>
+    where
+        I: IntoIterator<Item = OutputId>,
+    {
+        self.call_many(
+            output_ids
+                .into_iter()
+                .map(|output_id| GetOutputMetadata { output_id }),
+        )
+    }
+
+    /// The block including a transaction in the ledger.
+    pub fn get_included_block(&self, transaction_id: TransactionId) -> Result<Block> {
+        self.call(&GetIncludedBlock { transaction_id })
+    }
+
+    /// The block including a transaction in the ledger, serialized with the binary serializer.
+    pub fn get_included_block_raw(&self, transaction_id: TransactionId) -> Result<Vec<u8>> {
+        self.call(&GetIncludedBlockRaw { transaction_id })
+    }
+
+    /// The state of a transaction in the ledger, or [`TransactionStatus::Unknown`] if the node
+    /// knows no block including it.
+    pub fn transaction_status(&self, transaction_id: TransactionId) -> Result<TransactionStatus> {
+        match self.call(&GetIncludedBlockMetadata { transaction_id }) {
+            Ok(metadata) => TransactionStatus::try_from(&metadata),
+            Err(Error::NodeError {
+                kind: NodeErrorKind::NotFound,
+                ..
+            }) => Ok(TransactionStatus::Unknown),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// A milestone payload, by its [ID](crate::types::MilestoneId) or by its index.
+    pub fn get_milestone<M: Into<MilestoneRef>>(&self, milestone: M) -> Result<MilestonePayload> {
+        self.call(&GetMilestone {
+            milestone: milestone.into(),
+        })
+    }
+
+    /// A milestone payload, serialized with the binary serializer.
+    pub fn get_milestone_raw<M: Into<MilestoneRef>>(&self, milestone: M) -> Result<Vec<u8>> {
+        self.call(&GetMilestoneRaw {
+            milestone: milestone.into(),
+        })
+    }
+
+    /// The outputs created and consumed by a milestone, to follow the ledger milestone by
+    /// milestone.
+    pub fn get_utxo_changes<M: Into<MilestoneRef>>(
+        &self,
+        milestone: M,
+    ) -> Result<UtxoChangesResponse> {
+        self.call(&GetUtxoChanges {
+            milestone: milestone.into(),
+        })
+    }
+
+    /// All receipts of funds migrated from the legacy network.
+    pub fn receipts(&self) -> Result<ReceiptsResponse> {
+        self.call(&Receipts)
+    }
+
+    /// The receipts of funds migrated at the legacy milestone `migrated_at`.
+    pub fn receipts_migrated_at(&self, migrated_at: u32) -> Result<ReceiptsResponse> {
+        self.call(&ReceiptsMigratedAt { migrated_at })
+    }
+
+    /// The current treasury output.
+    pub fn treasury(&self) -> Result<TreasuryResponse> {
+        self.call(&Treasury)
+    }
+
+    /// The peers of the node at `node`, an index into [`nodes`](Self::nodes).
+    ///
+    /// Peers are specific to each node, so peer methods are sent to the given node only, without
+    /// retries. Nodes usually protect these routes with [`Auth::Jwt`](crate::types::Auth::Jwt).
+    pub fn peers(&self, node: usize) -> Result<Vec<Peer>> {
+        self.call_on(&Peers, node)
+    }
+
+    /// A peer of the node at `node`.
+    pub fn peer(&self, node: usize, peer_id: &str) -> Result<Peer> {
+        self.call_on(
+            &GetPeer {
+                peer_id: peer_id.into(),
+            },
+            node,
+        )
+    }
+
+    /// Add a peer reachable at `multi_address` to the node at `node`.
+    pub fn add_peer(&self, node: usize, multi_address: &str, alias: Option<&str>) -> Result<Peer> {
+        self.call_on(
+            &AddPeer {
+                multi_address: multi_address.into(),
+                alias: alias.map(Into::into),
+            },
+            node,
+        )
+    }
+
+    /// Remove a peer from the node at `node`.
+    pub fn remove_peer(&self, node: usize, peer_id: &str) -> Result<()> {
+        self.call_on(
+            &RemovePeer {
+                peer_id: peer_id.into(),
+            },
+            node,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::Method;
+    use crate::comm::http::fake::FakeHttpClient;
+    use crate::types::{
+        ApiVersion, Error, Node, NodeErrorKind, NodeInfo, TransactionId, TransactionStatus,
+    };
+    use crate::Client;
+    use alloc::sync::Arc;
+    use alloc::vec;
+
+    const NODE: &str = "https://node.example.iota.org";
+    const TRANSACTION: &str = "0x5f6e7d8c9b0a1f2e3d4c5b6a7980f1e2d3c4b5a697887766554433221100ffee";
+    const METADATA: &str = "https://node.example.iota.org/api/v2/transactions/0x5f6e7d8c9b0a1f2e3d4c5b6a7980f1e2d3c4b5a697887766554433221100ffee/included-block/metadata";
+
+    fn client(http: &Arc<FakeHttpClient>) -> Client {
+        http.node(NODE, &["core/v2"]);
+        Client::builder()
+            .nodes(vec![NODE.parse::<Node>().unwrap()])
+            .http(http.clone())
+            .retries(0)
+            .build()
+    }
+
+    #[test]
+    fn transaction_status_of_unknown_transaction() {
+        let http = Arc::new(FakeHttpClient::new());
+        let client = client(&http);
+        let transaction_id: TransactionId = TRANSACTION.parse().unwrap();
+
+        assert_eq!(
+            client.transaction_status(transaction_id),
+            Ok(TransactionStatus::Unknown)
+        );
+        assert_eq!(http.count(METADATA), 1);
+    }
+
+    #[test]
+    fn transaction_status_keeps_other_node_errors() {
+        let http = Arc::new(FakeHttpClient::new());
+        http.respond(
+            Method::Get,
+            METADATA,
+            503,
+            r#"{"error":{"code":"503","message":"service unavailable: node is not synced"}}"#,
+        );
+        let client = client(&http);
+        let transaction_id: TransactionId = TRANSACTION.parse().unwrap();
+
+        let err = client.transaction_status(transaction_id).unwrap_err();
+
+        assert_eq!(
+            err.node_error_kind(),
+            Some(NodeErrorKind::ServiceUnavailable)
+        );
+    }
+
+    #[test]
+    fn transaction_status_keeps_transport_errors() {
+        let http = Arc::new(FakeHttpClient::new());
+        http.fail(
+            Method::Get,
+            METADATA,
+            Error::HttpClientError("connection refused".into()),
+        );
+        let client = client(&http);
+        let transaction_id: TransactionId = TRANSACTION.parse().unwrap();
+
+        assert_eq!(
+            client.transaction_status(transaction_id),
+            Err(Error::HttpClientError("connection refused".into()))
+        );
+    }
+
+    #[test]
+    fn v2_calls_skip_chrysalis_nodes() {
+        const CHRYSALIS: &str = "https://chrysalis.example.iota.org";
+        let http = Arc::new(FakeHttpClient::new());
+        http.chrysalis_node(CHRYSALIS)
+            .node(NODE, &["core/v2"])
+            .respond(
+                Method::Get,
+                "https://node.example.iota.org/api/v2/tips",
+                200,
+                r#"{"tips":[]}"#,
+            );
+        let client = Client::builder()
+            .nodes(vec![
+                CHRYSALIS.parse::<Node>().unwrap(),
+                NODE.parse::<Node>().unwrap(),
+            ])
+            .http(http.clone())
+            .build();
+
+        assert_eq!(client.api_version(), Ok(ApiVersion::V1));
+        assert!(client.tips().unwrap().tips.is_empty());
+        assert_eq!(
+            http.count("https://chrysalis.example.iota.org/api/v2/tips"),
+            0
+        );
+        assert!(matches!(client.info(), Ok(NodeInfo::Chrysalis(_))));
+        assert_eq!(http.count("https://node.example.iota.org/api/v1/info"), 0);
     }
 }
@@ -2,6 +2,16 @@
 //!
 //! [event]: https://github.com/iotaledger/tips/blob/main/tips/TIP-0016/tip-0016.md
 
+use crate::client::discovery;
+use crate::types::{Capability, Result};
 use crate::Client;
+use alloc::string::String;
 
-impl Client {}
+impl Client {
+    /// The WebSocket URL of the MQTT event API on the preferred node offering it.
+    pub fn mqtt_url(&self) -> Result<String> {
+        let index = self.nodes_offering(Capability::Mqtt, 1)?[0];
+
+        Ok(discovery::mqtt_url(&self.nodes[index].url))
+    }
+}
@@ -1,7 +1,16 @@
 //! Implementation of [the Faucet plugin API][faucet] for [Client].
 //!
-//! [faucet]: #
+//! [faucet]: https://github.com/iotaledger/inx-faucet
 
+use crate::api::faucet::Enqueue;
+use crate::types::{FaucetEnqueueResponse, Result};
 use crate::Client;
 
-impl Client {}
+impl Client {
+    /// Request funds from the faucet for `address`, on a node offering the faucet.
+    pub fn request_funds(&self, address: &str) -> Result<FaucetEnqueueResponse> {
+        self.call(&Enqueue {
+            address: address.into(),
+        })
+    }
+}
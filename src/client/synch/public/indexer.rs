@@ -2,6 +2,71 @@
 //!
 //! [indexer]: https://github.com/iotaledger/tips/pull/62
 
+use crate::api::indexer::BasicOutputs;
+use crate::types::{IndexerOutputsResponse, Result};
 use crate::Client;
 
-impl Client {}
+impl Client {
+    /// IDs of unspent basic outputs unlockable by `address`, on a node offering the indexer. Pass
+    /// the cursor of a page to get the next one.
+    pub fn basic_outputs(
+        &self,
+        address: &str,
+        cursor: Option<&str>,
+    ) -> Result<IndexerOutputsResponse> {
+        self.call(&BasicOutputs {
+            address: Some(address.into()),
+            cursor: cursor.map(Into::into),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::Method;
+    use crate::comm::http::fake::FakeHttpClient;
+    use crate::types::{Capability, Error, Node};
+    use crate::Client;
+    use alloc::sync::Arc;
+    use alloc::vec;
+
+    #[test]
+    fn plugin_calls_go_to_nodes_offering_the_plugin() {
+        let http = Arc::new(FakeHttpClient::new());
+        http.node("https://core.example.iota.org", &["core/v2"])
+            .node(
+                "https://indexer.example.iota.org",
+                &["core/v2", "indexer/v1", "mqtt/v1"],
+            )
+            .respond(
+                Method::Get,
+                "https://indexer.example.iota.org/api/indexer/v1/outputs/basic?address=atoi1qp&cursor=1.0x00.10",
+                200,
+                r#"{"ledgerIndex":480,"items":[]}"#,
+            );
+        let client = Client::builder()
+            .nodes(vec![
+                "https://core.example.iota.org".parse::<Node>().unwrap(),
+                "https://indexer.example.iota.org".parse::<Node>().unwrap(),
+            ])
+            .http(http.clone())
+            .build();
+
+        let page = client.basic_outputs("atoi1qp", Some("1.0x00.10")).unwrap();
+        assert_eq!(page.ledger_index, 480);
+        assert_eq!(page.cursor, None);
+
+        assert_eq!(
+            client.mqtt_url().unwrap(),
+            "wss://indexer.example.iota.org/api/mqtt/v1"
+        );
+        assert_eq!(
+            client.participation_events(),
+            Err(Error::UnsupportedByNode(Capability::Participation))
+        );
+        assert!(http
+            .requests()
+            .iter()
+            .all(|(_, url)| !url.starts_with("https://core.example.iota.org/api/indexer")));
+    }
+}
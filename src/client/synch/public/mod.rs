@@ -2,7 +2,15 @@
 //!
 //! [Client]: super::Client
 
+#[cfg(feature = "std")]
+mod confirmation;
+mod control;
 mod core;
 mod event;
 mod faucet;
 mod indexer;
+mod participation;
+#[cfg(feature = "std")]
+mod probe;
+mod promotion;
+mod v1;
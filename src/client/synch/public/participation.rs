@@ -0,0 +1,14 @@
+//! Implementation of [the participation plugin API][participation] for [Client].
+//!
+//! [participation]: https://github.com/iotaledger/inx-participation
+
+use crate::api::participation::Events;
+use crate::types::{ParticipationEventsResponse, Result};
+use crate::Client;
+
+impl Client {
+    /// The participation events tracked by a node offering the participation plugin.
+    pub fn participation_events(&self) -> Result<ParticipationEventsResponse> {
+        self.call(&Events)
+    }
+}
@@ -0,0 +1,126 @@
+//! Probing of the configured nodes of [Client].
+
+use crate::api::core::Health;
+use crate::types::{Error, NodeProbe, ProbeReport};
+use crate::Client;
+use alloc::vec::Vec;
+use std::time::Instant;
+
+impl Client {
+    /// Probe every configured node for its latency, health, sync lag and capabilities, on a
+    /// thread each, and rank them.
+    ///
+    /// ```
+    /// use atoi::comm::http::DummyHttpClient;
+    /// use atoi::types::Node;
+    /// use atoi::Client;
+    /// use std::sync::Arc;
+    ///
+    /// let client = Client::builder()
+    ///     .nodes(vec![
+    ///         "https://unreachable.example.iota.org".parse::<Node>().unwrap(),
+    ///         "https://example.iota.org".parse::<Node>().unwrap(),
+    ///     ])
+    ///     .http(Arc::new(DummyHttpClient::new()))
+    ///     .build();
+    /// let report = client.probe();
+    ///
+    /// let best = report.best().unwrap();
+    /// assert_eq!(best.node.url.as_str(), "https://example.iota.org");
+    /// assert_eq!(best.sync_lag, Some(0));
+    /// assert!(best.supports_pow());
+    /// assert_eq!(report.usable_nodes().len(), 1);
+    /// ```
+    pub fn probe(&self) -> ProbeReport {
+        let probes: Vec<_> = (0..self.nodes.len())
+            .map(|index| {
+                let client = self.clone();
+                std::thread::spawn(move || client.probe_at(index))
+            })
+            .collect();
+
+        ProbeReport::new(
+            probes
+                .into_iter()
+                .map(|probe| match probe.join() {
+                    Ok(probe) => probe,
+                    Err(panic) => std::panic::resume_unwind(panic),
+                })
+                .collect(),
+        )
+    }
+
+    fn probe_at(&self, index: usize) -> NodeProbe {
+        let mut probe = NodeProbe::new(self.nodes[index].clone());
+
+        let started_at = Instant::now();
+        match self.call_on(&Health, index) {
+            Ok(()) => {
+                probe.latency = Some(started_at.elapsed());
+                probe.healthy = true;
+            }
+            // The node is unreachable: do not wait for it again.
+            Err(err @ Error::HttpClientError(_)) => {
+                probe.record_error(err);
+                return probe;
+            }
+            Err(err) => probe.record_error(err),
+        }
+
+        match self.discover_at(index) {
+            Ok((info, capabilities)) => {
+                probe.record_info(&info);
+                probe.capabilities = Some(capabilities);
+            }
+            Err(err) => probe.record_error(err),
+        }
+
+        probe
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::Method;
+    use crate::comm::http::fake::FakeHttpClient;
+    use crate::types::{Error, Node};
+    use crate::Client;
+    use alloc::sync::Arc;
+    use alloc::vec;
+
+    #[test]
+    fn unreachable_nodes_are_asked_once() {
+        const DOWN: &str = "https://down.example.iota.org";
+        const UP: &str = "https://up.example.iota.org";
+        let http = Arc::new(FakeHttpClient::new());
+        http.fail(
+            Method::Get,
+            "https://down.example.iota.org/health",
+            Error::HttpClientError("connection timed out".into()),
+        )
+        .node(UP, &["core/v2"])
+        .respond(Method::Get, "https://up.example.iota.org/health", 200, "");
+        let client = Client::builder()
+            .nodes(vec![
+                DOWN.parse::<Node>().unwrap(),
+                UP.parse::<Node>().unwrap(),
+            ])
+            .http(http.clone())
+            .build();
+
+        let report = client.probe();
+
+        assert_eq!(report.best().map(|probe| probe.node.url.as_str()), Some(UP));
+        assert_eq!(
+            report.probes[1].error,
+            Some(Error::HttpClientError("connection timed out".into()))
+        );
+        let down_requests = http
+            .requests()
+            .into_iter()
+            .filter(|(_, url)| url.starts_with(DOWN))
+            .count();
+        assert_eq!(down_requests, 1);
+        assert_eq!(http.count("https://up.example.iota.org/api/v2/info"), 1);
+    }
+}
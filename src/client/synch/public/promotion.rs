@@ -0,0 +1,357 @@
+//! Promotion and reattachment of blocks for [Client].
+
+#[cfg(feature = "std")]
+use crate::api::{core::GetBlockMetadata, Uncached};
+use crate::types::{Block, BlockId, Parents, Result};
+#[cfg(feature = "std")]
+use crate::types::{PromotionError, PromotionPolicy, PromotionReport, Reaction};
+use crate::Client;
+#[cfg(feature = "std")]
+use alloc::boxed::Box;
+
+impl Client {
+    /// Issue a block without payload approving `block_id` and fresh tips, to get `block_id`
+    /// referenced sooner. Returns the ID of the promotion block.
+    pub fn promote(&self, block_id: BlockId) -> Result<BlockId> {
+        let parents = Parents::including(block_id, self.tips()?.tips)?;
+
+        self.submit_block(Block::new(parents, None))
+    }
+
+    /// Attach the payload of `block_id` again, in a new block with fresh parents. Returns the ID
+    /// of the new block.
+    pub fn reattach(&self, block_id: BlockId) -> Result<BlockId> {
+        let block = self.get_block(block_id)?;
+
+        self.submit_block(Block::new(self.parents()?, block.payload))
+    }
+
+    /// Watch `block_id` until it or one of its reattachments is referenced, promoting or
+    /// reattaching it as the node advises, within the budgets of `policy`.
+    ///
+    /// Running out of budget or time is not an error: the returned report then tells no
+    /// attachment got referenced. Attachments unknown to the node yet and transient errors are
+    /// waited out; other errors are returned with the report of what was done until then.
+    #[cfg(feature = "std")]
+    pub fn promote_until_referenced(
+        &self,
+        block_id: BlockId,
+        policy: &PromotionPolicy,
+    ) -> core::result::Result<PromotionReport, PromotionError> {
+        let started_at = std::time::Instant::now();
+        let mut report = PromotionReport::new(block_id);
+
+        loop {
+            let mut latest = None;
+            for attachment in report.attachments().to_vec() {
+                match self.call(&Uncached(&GetBlockMetadata {
+                    block_id: attachment,
+                })) {
+                    Ok(metadata) if metadata.is_referenced() => {
+                        report.referenced = Some(metadata);
+                        return Ok(report);
+                    }
+                    Ok(metadata) => latest = Some(metadata),
+                    // Not spread to the node yet, or the node is unavailable: check again later.
+                    Err(error) if error.may_clear() => latest = None,
+                    Err(error) => {
+                        return Err(PromotionError {
+                            error,
+                            report: Box::new(report),
+                        })
+                    }
+                }
+            }
+
+            if let Some(metadata) = latest {
+                let reacted = match policy.react(&metadata, &report) {
+                    Reaction::Wait => Ok(()),
+                    Reaction::Promote => self
+                        .promote(metadata.block_id)
+                        .map(|promotion| report.promoted(promotion)),
+                    Reaction::Reattach => self
+                        .reattach(metadata.block_id)
+                        .map(|reattachment| report.reattached(reattachment)),
+                    Reaction::GiveUp => return Ok(report),
+                };
+                match reacted {
+                    Err(error) if !error.is_transient() => {
+                        return Err(PromotionError {
+                            error,
+                            report: Box::new(report),
+                        })
+                    }
+                    _ => {}
+                }
+            }
+
+            if let Some(timeout) = policy.timeout {
+                match timeout.checked_sub(started_at.elapsed()) {
+                    Some(left) => std::thread::sleep(policy.interval.min(left)),
+                    None => return Ok(report),
+                }
+            } else {
+                std::thread::sleep(policy.interval);
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::api::{MediaType, Method};
+    use crate::comm::http::fake::FakeHttpClient;
+    use crate::comm::http::{DummyHttpClient, HttpClient};
+    use crate::types::{BlockId, Error, Node, NodeErrorKind, PromotionAction, PromotionPolicy};
+    use crate::Client;
+    use alloc::string::String;
+    use alloc::sync::Arc;
+    use alloc::vec;
+    use core::time::Duration;
+
+    const NODE: &str = "https://node.example.iota.org";
+    const STUCK: &str = "0x4c7a5e2d9b1f3a6c8e0d2b4f6a8c0e2d4b6f8a0c2e4d6b8f0a2c4e6d8b0f2a4c";
+    const PROMOTION: &str = "0x1111111111111111111111111111111111111111111111111111111111111111";
+    const REATTACHMENT: &str = "0x2222222222222222222222222222222222222222222222222222222222222222";
+
+    fn metadata(block_id: &str, state: &str) -> String {
+        alloc::format!(
+            r#"{{"blockId":"{}","parents":[],"isSolid":true,{}}}"#,
+            block_id,
+            state
+        )
+    }
+
+    #[test]
+    fn promotes_then_reattaches_until_referenced() {
+        let http = Arc::new(FakeHttpClient::new());
+        let stuck_block = HttpClient::get(
+            &DummyHttpClient::new(),
+            &alloc::format!("https://example.iota.org/api/v2/blocks/{}", STUCK),
+            None,
+            MediaType::Json,
+        )
+        .unwrap()
+        .body;
+        let stuck_metadata = alloc::format!("{}/api/v2/blocks/{}/metadata", NODE, STUCK);
+        let blocks = alloc::format!("{}/api/v2/blocks", NODE);
+        http.node(NODE, &["core/v2"])
+            .respond(
+                Method::Get,
+                &stuck_metadata,
+                200,
+                &metadata(STUCK, r#""shouldPromote":true,"shouldReattach":false"#),
+            )
+            .respond(
+                Method::Get,
+                &stuck_metadata,
+                200,
+                &metadata(STUCK, r#""shouldPromote":false,"shouldReattach":true"#),
+            )
+            .respond(
+                Method::Get,
+                &stuck_metadata,
+                200,
+                &metadata(STUCK, r#""shouldPromote":false,"shouldReattach":true"#),
+            )
+            .respond(
+                Method::Get,
+                &alloc::format!("{}/api/v2/blocks/{}", NODE, STUCK),
+                200,
+                core::str::from_utf8(&stuck_block).unwrap(),
+            )
+            .respond(
+                Method::Get,
+                &alloc::format!("{}/api/v2/tips", NODE),
+                200,
+                r#"{"tips":["0x3b0c7e9f2d1a8b6c5e4f3a2b1c0d9e8f7a6b5c4d3e2f1a0b9c8d7e6f5a4b3c2d"]}"#,
+            )
+            .respond(
+                Method::Post,
+                &blocks,
+                201,
+                &alloc::format!(r#"{{"blockId":"{}"}}"#, PROMOTION),
+            )
+            .respond(
+                Method::Post,
+                &blocks,
+                201,
+                &alloc::format!(r#"{{"blockId":"{}"}}"#, REATTACHMENT),
+            )
+            .respond(
+                Method::Get,
+                &alloc::format!("{}/api/v2/blocks/{}/metadata", NODE, REATTACHMENT),
+                200,
+                &metadata(REATTACHMENT, r#""referencedByMilestoneIndex":481"#),
+            );
+        let client = Client::builder()
+            .nodes(vec![NODE.parse::<Node>().unwrap()])
+            .http(http.clone())
+            .build();
+        let policy = PromotionPolicy::builder()
+            .interval(Duration::from_millis(1))
+            .timeout(Duration::from_secs(10))
+            .build();
+        let stuck: BlockId = STUCK.parse().unwrap();
+        let reattachment: BlockId = REATTACHMENT.parse().unwrap();
+
+        let report = client.promote_until_referenced(stuck, &policy).unwrap();
+
+        assert_eq!(
+            report.actions,
+            vec![
+                PromotionAction::Promoted {
+                    block_id: stuck,
+                    promotion: PROMOTION.parse().unwrap(),
+                },
+                PromotionAction::Reattached {
+                    block_id: stuck,
+                    reattachment,
+                },
+            ]
+        );
+        assert_eq!(
+            report.referenced.map(|metadata| metadata.block_id),
+            Some(reattachment)
+        );
+        assert_eq!(http.count(&blocks), 2);
+    }
+
+    #[test]
+    fn gives_up_beyond_the_reattachment_budget() {
+        let http = Arc::new(FakeHttpClient::new());
+        http.node(NODE, &["core/v2"]).respond(
+            Method::Get,
+            &alloc::format!("{}/api/v2/blocks/{}/metadata", NODE, STUCK),
+            200,
+            &metadata(STUCK, r#""shouldPromote":false,"shouldReattach":true"#),
+        );
+        let client = Client::builder()
+            .nodes(vec![NODE.parse::<Node>().unwrap()])
+            .http(http.clone())
+            .build();
+        let policy = PromotionPolicy::builder()
+            .interval(Duration::from_millis(1))
+            .max_reattachments(0)
+            .build();
+
+        let report = client
+            .promote_until_referenced(STUCK.parse().unwrap(), &policy)
+            .unwrap();
+
+        assert!(!report.is_referenced());
+        assert!(report.actions.is_empty());
+        assert_eq!(http.count(&alloc::format!("{}/api/v2/blocks", NODE)), 0);
+    }
+
+    /// Script `http` for a reattachment of the stuck block, answered with `REATTACHMENT`.
+    fn reattachable(http: &FakeHttpClient) -> &FakeHttpClient {
+        let stuck_block = HttpClient::get(
+            &DummyHttpClient::new(),
+            &alloc::format!("https://example.iota.org/api/v2/blocks/{}", STUCK),
+            None,
+            MediaType::Json,
+        )
+        .unwrap()
+        .body;
+
+        http.node(NODE, &["core/v2"])
+            .respond(
+                Method::Get,
+                &alloc::format!("{}/api/v2/blocks/{}/metadata", NODE, STUCK),
+                200,
+                &metadata(STUCK, r#""shouldPromote":false,"shouldReattach":true"#),
+            )
+            .respond(
+                Method::Get,
+                &alloc::format!("{}/api/v2/blocks/{}", NODE, STUCK),
+                200,
+                core::str::from_utf8(&stuck_block).unwrap(),
+            )
+            .respond(
+                Method::Get,
+                &alloc::format!("{}/api/v2/tips", NODE),
+                200,
+                r#"{"tips":["0x3b0c7e9f2d1a8b6c5e4f3a2b1c0d9e8f7a6b5c4d3e2f1a0b9c8d7e6f5a4b3c2d"]}"#,
+            )
+            .respond(
+                Method::Post,
+                &alloc::format!("{}/api/v2/blocks", NODE),
+                201,
+                &alloc::format!(r#"{{"blockId":"{}"}}"#, REATTACHMENT),
+            )
+    }
+
+    #[test]
+    fn waits_for_reattachments_to_reach_the_node() {
+        let http = Arc::new(FakeHttpClient::new());
+        let reattachment_metadata =
+            alloc::format!("{}/api/v2/blocks/{}/metadata", NODE, REATTACHMENT);
+        reattachable(&http)
+            .respond(Method::Get, &reattachment_metadata, 404, "")
+            .respond(Method::Get, &reattachment_metadata, 503, "")
+            .respond(
+                Method::Get,
+                &reattachment_metadata,
+                200,
+                &metadata(REATTACHMENT, r#""referencedByMilestoneIndex":481"#),
+            );
+        let client = Client::builder()
+            .nodes(vec![NODE.parse::<Node>().unwrap()])
+            .http(http.clone())
+            .retries(0)
+            .build();
+        let policy = PromotionPolicy::builder()
+            .interval(Duration::from_millis(1))
+            .timeout(Duration::from_secs(10))
+            .build();
+
+        let report = client
+            .promote_until_referenced(STUCK.parse().unwrap(), &policy)
+            .unwrap();
+
+        assert_eq!(report.reattachments(), 1);
+        assert_eq!(
+            report.referenced.map(|metadata| metadata.block_id),
+            Some(REATTACHMENT.parse().unwrap())
+        );
+        assert_eq!(http.count(&reattachment_metadata), 3);
+    }
+
+    #[test]
+    fn returns_the_report_with_fatal_errors() {
+        let http = Arc::new(FakeHttpClient::new());
+        reattachable(&http).respond(
+            Method::Get,
+            &alloc::format!("{}/api/v2/blocks/{}/metadata", NODE, REATTACHMENT),
+            403,
+            "",
+        );
+        let client = Client::builder()
+            .nodes(vec![NODE.parse::<Node>().unwrap()])
+            .http(http.clone())
+            .build();
+        let policy = PromotionPolicy::builder()
+            .interval(Duration::from_millis(1))
+            .build();
+        let stuck: BlockId = STUCK.parse().unwrap();
+
+        let error = client.promote_until_referenced(stuck, &policy).unwrap_err();
+
+        assert_eq!(
+            error.error.node_error_kind(),
+            Some(NodeErrorKind::Forbidden)
+        );
+        assert_eq!(
+            error.report.actions,
+            vec![PromotionAction::Reattached {
+                block_id: stuck,
+                reattachment: REATTACHMENT.parse().unwrap(),
+            }]
+        );
+        assert!(matches!(
+            Error::from(error),
+            Error::NodeError { code: 403, .. }
+        ));
+    }
+}
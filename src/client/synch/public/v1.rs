@@ -0,0 +1,20 @@
+//! Implementation of the Chrysalis REST API (v1) for [Client], on nodes speaking it.
+
+use crate::api::v1::{Message, MessagesByIndex};
+use crate::types::v1::{Message as MessageResponse, MessageId, MessagesByIndexResponse};
+use crate::types::Result;
+use crate::Client;
+
+impl Client {
+    /// A Chrysalis message by its ID.
+    pub fn message(&self, message_id: MessageId) -> Result<MessageResponse> {
+        self.call(&Message { message_id })
+    }
+
+    /// IDs of the Chrysalis messages with an indexation payload of `index`.
+    pub fn messages_by_index(&self, index: &[u8]) -> Result<MessagesByIndexResponse> {
+        self.call(&MessagesByIndex {
+            index: index.into(),
+        })
+    }
+}
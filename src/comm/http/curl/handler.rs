@@ -6,17 +6,48 @@
 ///
 /// [`CurlHttpClient`]: super::CurlHttpClient
 #[derive(Debug, Default)]
-pub(super) struct CurlHttpClientHandler(pub(super) Vec<u8>);
+pub(super) struct CurlHttpClientHandler {
+    /// The response body.
+    pub(super) body: Vec<u8>,
+    /// The value of the `Retry-After` response header, in seconds.
+    pub(super) retry_after: Option<u64>,
+    /// The value of the `Location` response header.
+    pub(super) location: Option<String>,
+}
 
 impl curl::easy::Handler for CurlHttpClientHandler {
     fn write(&mut self, data: &[u8]) -> Result<usize, curl::easy::WriteError> {
-        self.0.extend(data);
+        self.body.extend(data);
         Ok(data.len())
     }
+
+    fn header(&mut self, data: &[u8]) -> bool {
+        if let Some((name, value)) = core::str::from_utf8(data)
+            .ok()
+            .and_then(|line| line.split_once(':'))
+        {
+            let name = name.trim();
+
+            if name.eq_ignore_ascii_case("retry-after") {
+                self.retry_after = value.trim().parse().ok();
+            } else if name.eq_ignore_ascii_case("location") {
+                self.location = Some(value.trim().into());
+            }
+        }
+
+        true
+    }
 }
 
 impl CurlHttpClientHandler {
     pub fn new() -> Self {
         Default::default()
     }
+
+    /// Forget about the last response.
+    pub fn clear(&mut self) {
+        self.body.clear();
+        self.retry_after = None;
+        self.location = None;
+    }
 }
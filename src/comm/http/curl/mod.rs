@@ -3,9 +3,13 @@
 mod handler;
 
 use self::handler::CurlHttpClientHandler;
+use crate::api::{MediaType, Response};
 use crate::comm::http::HttpClient;
-use crate::types::{Auth, Result};
-use curl::easy::Easy2;
+use crate::types::{Auth, Error, Result};
+use core::time::Duration;
+use curl::easy::{Easy2, List};
+use std::sync::Mutex;
+use typed_builder::TypedBuilder;
 
 /// The default user agent string.
 const USER_AGENT: &str = concat!(
@@ -17,19 +21,113 @@ const USER_AGENT: &str = concat!(
 );
 
 /// HTTP communication backend using libcurl.
-#[derive(Debug, Default)]
-pub struct CurlHttpClient {}
+///
+/// Handles are kept in a pool across requests, so connections to nodes are reused, while
+/// concurrent requests each get a handle of their own.
+#[derive(Debug, TypedBuilder)]
+#[builder(field_defaults(default, setter(strip_option)))]
+pub struct CurlHttpClient {
+    /// Timeout of a whole request, including connection establishment.
+    pub timeout: Option<Duration>,
+    /// Handles not used by any request in flight.
+    #[builder(setter(skip))]
+    idle: Mutex<Vec<Easy2<CurlHttpClientHandler>>>,
+}
 
 impl HttpClient for CurlHttpClient {
-    fn get(&self, url: &str, auth: Option<&Auth>) -> Result<Vec<u8>> {
-        let mut handle = Easy2::new(CurlHttpClientHandler::new());
-        let mut headers = curl::easy::List::new();
+    fn get(&self, url: &str, auth: Option<&Auth>, accept: MediaType) -> Result<Response> {
+        self.perform(url, auth, accept, |handle, _| {
+            handle.get(true)?;
+            Ok(())
+        })
+    }
+
+    fn post(
+        &self,
+        url: &str,
+        auth: Option<&Auth>,
+        body: &[u8],
+        content_type: MediaType,
+    ) -> Result<Response> {
+        self.perform(url, auth, MediaType::Json, |handle, headers| {
+            handle.post(true)?;
+            handle.post_fields_copy(body)?;
+            headers.append(&format!("Content-Type: {}", content_type.as_str()))?;
+            Ok(())
+        })
+    }
+
+    fn delete(&self, url: &str, auth: Option<&Auth>) -> Result<Response> {
+        self.perform(url, auth, MediaType::Json, |handle, _| {
+            handle.custom_request("DELETE")?;
+            Ok(())
+        })
+    }
+}
+
+impl Default for CurlHttpClient {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+impl CurlHttpClient {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Perform a request on an idle handle, or a new one if all are in use, after setting it up
+    /// with `method`. The handle returns to the pool afterwards.
+    fn perform<F>(
+        &self,
+        url: &str,
+        auth: Option<&Auth>,
+        accept: MediaType,
+        method: F,
+    ) -> Result<Response>
+    where
+        F: FnOnce(&mut Easy2<CurlHttpClientHandler>, &mut List) -> Result<()>,
+    {
+        let mut handle = self
+            .idle
+            .lock()
+            .map_err(|_| Error::HttpClientError("curl handle pool poisoned".into()))?
+            .pop()
+            .unwrap_or_else(|| Easy2::new(CurlHttpClientHandler::new()));
+
+        let response = self.perform_on(&mut handle, url, auth, accept, method);
+
+        if let Ok(mut idle) = self.idle.lock() {
+            idle.push(handle);
+        }
+
+        response
+    }
+
+    fn perform_on<F>(
+        &self,
+        handle: &mut Easy2<CurlHttpClientHandler>,
+        url: &str,
+        auth: Option<&Auth>,
+        accept: MediaType,
+        method: F,
+    ) -> Result<Response>
+    where
+        F: FnOnce(&mut Easy2<CurlHttpClientHandler>, &mut List) -> Result<()>,
+    {
+        let mut headers = List::new();
 
         handle.reset();
+        handle.get_mut().clear();
 
-        handle.get(true)?;
+        method(handle, &mut headers)?;
         handle.url(url)?;
         handle.useragent(USER_AGENT)?;
+        headers.append(&format!("Accept: {}", accept.as_str()))?;
+
+        if let Some(timeout) = self.timeout {
+            handle.timeout(timeout)?;
+        }
 
         match auth {
             Some(Auth::Basic { username, password }) => {
@@ -46,23 +144,54 @@ impl HttpClient for CurlHttpClient {
         handle.http_headers(headers)?;
         handle.verbose(true)?;
 
-        handle.perform()?;
+        if let Err(err) = handle.perform() {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(url, error = %err, "curl request failed");
 
-        let handler = handle.get_ref();
-        Ok(handler.0.clone())
-    }
+            return Err(err.into());
+        }
 
-    fn post(&self, _url: &str, _auth: Option<&Auth>, _body: &[u8]) -> Result<Vec<u8>> {
-        todo!()
-    }
+        #[cfg(feature = "tracing")]
+        tracing::trace!(
+            url,
+            connection_reused = matches!(handle.num_connects(), Ok(0)),
+            "curl request finished"
+        );
 
-    fn delete(&self, _url: &str, _auth: Option<&Auth>) -> Result<Vec<u8>> {
-        todo!()
+        match handle.response_code()? {
+            429 => Err(Error::RateLimited {
+                url: url.into(),
+                retry_after: handle.get_ref().retry_after,
+            }),
+            code @ 400..=599 => Err(Error::from_response(
+                url,
+                code as u16,
+                &handle.get_ref().body,
+            )),
+            _ => Ok(Response {
+                body: core::mem::take(&mut handle.get_mut().body),
+                location: handle.get_mut().location.take(),
+            }),
+        }
     }
 }
 
-impl CurlHttpClient {
-    pub fn new() -> Self {
-        Default::default()
+#[cfg(test)]
+mod tests {
+    use super::CurlHttpClient;
+    use crate::api::MediaType;
+    use crate::comm::http::HttpClient;
+    use crate::types::Error;
+
+    #[test]
+    fn handles_return_to_the_pool_after_failures() {
+        let curl = CurlHttpClient::new();
+
+        for _ in 0..2 {
+            let result = curl.get("http://127.0.0.1:1/api/core/v2/info", None, MediaType::Json);
+            assert!(matches!(result, Err(Error::HttpClientError(_))));
+        }
+
+        assert_eq!(curl.idle.lock().unwrap().len(), 1);
     }
 }
@@ -2,9 +2,10 @@
 
 mod response;
 
-use self::response::respond_dummy;
+use self::response::{respond_dummy, respond_dummy_post};
 use super::{AsyncHttpClient, HttpClient};
-use crate::types::{Auth, Result};
+use crate::api::{MediaType, Response};
+use crate::types::{Auth, CancellationToken, Error, Result};
 use alloc::{boxed::Box, vec::Vec};
 use async_trait::async_trait;
 
@@ -13,31 +14,66 @@ use async_trait::async_trait;
 pub struct DummyHttpClient {}
 
 impl HttpClient for DummyHttpClient {
-    fn get(&self, url: &str, _auth: Option<&Auth>) -> Result<Vec<u8>> {
-        Ok(respond_dummy(url))
+    fn get(&self, url: &str, _auth: Option<&Auth>, accept: MediaType) -> Result<Response> {
+        Ok(respond_dummy(url, accept).into())
     }
 
-    fn post(&self, url: &str, _auth: Option<&Auth>, _body: &[u8]) -> Result<Vec<u8>> {
-        Ok(respond_dummy(url))
+    fn post(
+        &self,
+        url: &str,
+        _auth: Option<&Auth>,
+        _body: &[u8],
+        _content_type: MediaType,
+    ) -> Result<Response> {
+        Ok(respond_dummy_post(url).into())
     }
 
-    fn delete(&self, url: &str, _auth: Option<&Auth>) -> Result<Vec<u8>> {
-        Ok(respond_dummy(url))
+    fn delete(&self, _url: &str, _auth: Option<&Auth>) -> Result<Response> {
+        Ok(Response::default())
     }
 }
 
 #[async_trait]
 impl AsyncHttpClient for DummyHttpClient {
-    async fn get(&self, url: &str, _auth: Option<&Auth>) -> Result<Vec<u8>> {
-        Ok(respond_dummy(url))
+    async fn get(
+        &self,
+        url: &str,
+        _auth: Option<&Auth>,
+        accept: MediaType,
+        cancel: &CancellationToken,
+    ) -> Result<Response> {
+        unless_cancelled(cancel, || respond_dummy(url, accept))
     }
 
-    async fn post(&self, url: &str, _auth: Option<&Auth>, _body: &[u8]) -> Result<Vec<u8>> {
-        Ok(respond_dummy(url))
+    async fn post(
+        &self,
+        url: &str,
+        _auth: Option<&Auth>,
+        _body: &[u8],
+        _content_type: MediaType,
+        cancel: &CancellationToken,
+    ) -> Result<Response> {
+        unless_cancelled(cancel, || respond_dummy_post(url))
     }
 
-    async fn delete(&self, url: &str, _auth: Option<&Auth>) -> Result<Vec<u8>> {
-        Ok(respond_dummy(url))
+    async fn delete(
+        &self,
+        _url: &str,
+        _auth: Option<&Auth>,
+        cancel: &CancellationToken,
+    ) -> Result<Response> {
+        unless_cancelled(cancel, Vec::new)
+    }
+}
+
+fn unless_cancelled<F: FnOnce() -> Vec<u8>>(
+    cancel: &CancellationToken,
+    respond: F,
+) -> Result<Response> {
+    if cancel.is_cancelled() {
+        Err(Error::Cancelled)
+    } else {
+        Ok(respond().into())
     }
 }
 
@@ -1,8 +1,13 @@
 //! Dummy responses.
 
+use crate::api::MediaType;
 use alloc::vec::Vec;
 
-pub(super) fn respond_dummy(url: &str) -> Vec<u8> {
+pub(super) fn respond_dummy(url: &str, accept: MediaType) -> Vec<u8> {
+    if accept == MediaType::Binary {
+        return respond_dummy_binary(url);
+    }
+
     match url {
         "https://example.iota.org/health" => b"".as_slice(),
         "https://example.iota.org/api/v2/info" => br#"{
@@ -54,6 +59,370 @@ pub(super) fn respond_dummy(url: &str) -> Vec<u8> {
             ]
           }"#
         .as_slice(),
+        "https://example.iota.org/api/routes" => br#"{
+            "routes": [
+              "core/v2",
+              "indexer/v1",
+              "mqtt/v1"
+            ]
+          }"#
+        .as_slice(),
+        "https://example.iota.org/api/v2/tips" => br#"{
+            "tips": [
+              "0x9a1a0b1f81a4c25d9d6d0e8c4e6a2d8d4e0f5c2b1a3e7d6c5b4a39281706f5e4",
+              "0x3b0c7e9f2d1a8b6c5e4f3a2b1c0d9e8f7a6b5c4d3e2f1a0b9c8d7e6f5a4b3c2d",
+              "0x9a1a0b1f81a4c25d9d6d0e8c4e6a2d8d4e0f5c2b1a3e7d6c5b4a39281706f5e4"
+            ]
+          }"#
+        .as_slice(),
+        "https://example.iota.org/api/v2/blocks" => br#"{
+            "blockId": "0x9a1a0b1f81a4c25d9d6d0e8c4e6a2d8d4e0f5c2b1a3e7d6c5b4a39281706f5e4"
+          }"#
+        .as_slice(),
+        "https://example.iota.org/api/v2/blocks/\
+         0x9a1a0b1f81a4c25d9d6d0e8c4e6a2d8d4e0f5c2b1a3e7d6c5b4a39281706f5e4" => br#"{
+            "protocolVersion": 2,
+            "parents": [
+              "0x3b0c7e9f2d1a8b6c5e4f3a2b1c0d9e8f7a6b5c4d3e2f1a0b9c8d7e6f5a4b3c2d"
+            ],
+            "payload": {
+              "type": 5,
+              "tag": "0x61746f69",
+              "data": "0x68656c6c6f"
+            },
+            "nonce": "2305843009213869242"
+          }"#
+        .as_slice(),
+        "https://example.iota.org/api/v2/blocks/\
+         0x9a1a0b1f81a4c25d9d6d0e8c4e6a2d8d4e0f5c2b1a3e7d6c5b4a39281706f5e4/metadata" => br#"{
+            "blockId": "0x9a1a0b1f81a4c25d9d6d0e8c4e6a2d8d4e0f5c2b1a3e7d6c5b4a39281706f5e4",
+            "parents": [
+              "0x3b0c7e9f2d1a8b6c5e4f3a2b1c0d9e8f7a6b5c4d3e2f1a0b9c8d7e6f5a4b3c2d"
+            ],
+            "isSolid": true,
+            "referencedByMilestoneIndex": 480,
+            "ledgerInclusionState": "noTransaction",
+            "shouldPromote": false,
+            "shouldReattach": false
+          }"#
+        .as_slice(),
+        "https://example.iota.org/api/v2/blocks/\
+         0x4c7a5e2d9b1f3a6c8e0d2b4f6a8c0e2d4b6f8a0c2e4d6b8f0a2c4e6d8b0f2a4c" => br#"{
+            "protocolVersion": 2,
+            "parents": [
+              "0x1d2c3b4a59687f8e9d0c1b2a3f4e5d6c7b8a99887766554433221100ffeeddcc"
+            ],
+            "payload": {
+              "type": 5,
+              "tag": "0x61746f69",
+              "data": "0x68656c6c6f"
+            },
+            "nonce": "2305843009213869242"
+          }"#
+        .as_slice(),
+        "https://example.iota.org/api/v2/blocks/\
+         0x4c7a5e2d9b1f3a6c8e0d2b4f6a8c0e2d4b6f8a0c2e4d6b8f0a2c4e6d8b0f2a4c/metadata" => br#"{
+            "blockId": "0x4c7a5e2d9b1f3a6c8e0d2b4f6a8c0e2d4b6f8a0c2e4d6b8f0a2c4e6d8b0f2a4c",
+            "parents": [
+              "0x1d2c3b4a59687f8e9d0c1b2a3f4e5d6c7b8a99887766554433221100ffeeddcc"
+            ],
+            "isSolid": true,
+            "shouldPromote": false,
+            "shouldReattach": true
+          }"#
+        .as_slice(),
+        "https://example.iota.org/api/v2/outputs/\
+         0x5f6e7d8c9b0a1f2e3d4c5b6a7980f1e2d3c4b5a697887766554433221100ffee0000/metadata" => br#"{
+            "blockId": "0x9a1a0b1f81a4c25d9d6d0e8c4e6a2d8d4e0f5c2b1a3e7d6c5b4a39281706f5e4",
+            "transactionId": "0x5f6e7d8c9b0a1f2e3d4c5b6a7980f1e2d3c4b5a697887766554433221100ffee",
+            "outputIndex": 0,
+            "isSpent": true,
+            "milestoneIndexSpent": 479,
+            "milestoneTimestampSpent": 1617802002,
+            "transactionIdSpent": "0x0102030405060708091011121314151617181920212223242526272829303132",
+            "milestoneIndexBooked": 470,
+            "milestoneTimestampBooked": 1617801102,
+            "ledgerIndex": 480
+          }"#
+        .as_slice(),
+        "https://example.iota.org/api/v2/outputs/\
+         0x5f6e7d8c9b0a1f2e3d4c5b6a7980f1e2d3c4b5a697887766554433221100ffee0100/metadata" => br#"{
+            "blockId": "0x9a1a0b1f81a4c25d9d6d0e8c4e6a2d8d4e0f5c2b1a3e7d6c5b4a39281706f5e4",
+            "transactionId": "0x5f6e7d8c9b0a1f2e3d4c5b6a7980f1e2d3c4b5a697887766554433221100ffee",
+            "outputIndex": 1,
+            "isSpent": false,
+            "milestoneIndexBooked": 470,
+            "milestoneTimestampBooked": 1617801102,
+            "ledgerIndex": 480
+          }"#
+        .as_slice(),
+        "https://example.iota.org/api/v2/outputs/\
+         0x5f6e7d8c9b0a1f2e3d4c5b6a7980f1e2d3c4b5a697887766554433221100ffee0000" => br#"{
+            "metadata": {
+              "blockId": "0x9a1a0b1f81a4c25d9d6d0e8c4e6a2d8d4e0f5c2b1a3e7d6c5b4a39281706f5e4",
+              "transactionId": "0x5f6e7d8c9b0a1f2e3d4c5b6a7980f1e2d3c4b5a697887766554433221100ffee",
+              "outputIndex": 0,
+              "isSpent": true,
+              "milestoneIndexSpent": 479,
+              "milestoneTimestampSpent": 1617802002,
+              "transactionIdSpent": "0x0102030405060708091011121314151617181920212223242526272829303132",
+              "milestoneIndexBooked": 470,
+              "milestoneTimestampBooked": 1617801102,
+              "ledgerIndex": 480
+            },
+            "output": {
+              "type": 3,
+              "amount": "1000000",
+              "unlockConditions": [
+                {
+                  "type": 0,
+                  "address": {
+                    "type": 0,
+                    "pubKeyHash": "0x8eaf87ac1f52eb05f2c7c0c15502df990a228838dc37bd18de9503d69afd257d"
+                  }
+                }
+              ]
+            }
+          }"#
+        .as_slice(),
+        "https://example.iota.org/api/v2/transactions/\
+         0x5f6e7d8c9b0a1f2e3d4c5b6a7980f1e2d3c4b5a697887766554433221100ffee/included-block/metadata" => {
+            br#"{
+            "blockId": "0x9a1a0b1f81a4c25d9d6d0e8c4e6a2d8d4e0f5c2b1a3e7d6c5b4a39281706f5e4",
+            "parents": [
+              "0x3b0c7e9f2d1a8b6c5e4f3a2b1c0d9e8f7a6b5c4d3e2f1a0b9c8d7e6f5a4b3c2d"
+            ],
+            "isSolid": true,
+            "referencedByMilestoneIndex": 470,
+            "ledgerInclusionState": "included"
+          }"#
+            .as_slice()
+        }
+        "https://example.iota.org/api/v2/milestones/by-index/470"
+        | "https://example.iota.org/api/v2/milestones/\
+           0x7a09324557e9200f39bf493fc8fd6ac43e9ca750c6f6d884cc72386ddcb7d695" => {
+            br#"{
+            "type": 7,
+            "index": 470,
+            "timestamp": 1651234567,
+            "protocolVersion": 2,
+            "previousMilestoneId": "0x2b1e6b9f0c4d8a7e5f3c2d1b0a9e8f7c6d5b4a3928170f6e5d4c3b2a1908f7e6",
+            "parents": [
+              "0x3b0c7e9f2d1a8b6c5e4f3a2b1c0d9e8f7a6b5c4d3e2f1a0b9c8d7e6f5a4b3c2d"
+            ],
+            "inclusionMerkleRoot": "0x0e5751c026e543b2e8ab2eb06099daa1d1e5df47778f7787faab45cdf12fe3a8",
+            "appliedMerkleRoot": "0x0e5751c026e543b2e8ab2eb06099daa1d1e5df47778f7787faab45cdf12fe3a8",
+            "signatures": [
+              {
+                "type": 0,
+                "publicKey": "0xd85e5b1590d898d1e0cdebb2e3b5337c8b76270142663d78811683ba47c17c98",
+                "signature": "0x51306d5b3d3b5b21a2d1d18b4a3d1f0e5c86e4bd2e0e1a9b1c3d5e7f90a2b4c6d8e0f1a3b5c7d9e1f2a4b6c8d0e2f4a6b8c0d2e4f6a8b0c2d4e6f8a0b2c4d6e8f0"
+              }
+            ]
+          }"#
+            .as_slice()
+        }
+        "https://example.iota.org/api/v2/milestones/by-index/470/utxo-changes"
+        | "https://example.iota.org/api/v2/milestones/\
+           0x7a09324557e9200f39bf493fc8fd6ac43e9ca750c6f6d884cc72386ddcb7d695/utxo-changes" => {
+            br#"{
+            "index": 470,
+            "createdOutputs": [
+              "0x5f6e7d8c9b0a1f2e3d4c5b6a7980f1e2d3c4b5a697887766554433221100ffee0000",
+              "0x5f6e7d8c9b0a1f2e3d4c5b6a7980f1e2d3c4b5a697887766554433221100ffee0100"
+            ],
+            "consumedOutputs": [
+              "0x1c2d3e4f5a6b7c8d9e0f1a2b3c4d5e6f7a8b9c0d1e2f3a4b5c6d7e8f9a0b1c2d0000"
+            ]
+          }"#
+            .as_slice()
+        }
+        "https://example.iota.org/api/v2/receipts"
+        | "https://example.iota.org/api/v2/receipts/3245000" => br#"{
+            "receipts": [
+              {
+                "receipt": {
+                  "type": 0,
+                  "migratedAt": 3245000,
+                  "final": true,
+                  "funds": [
+                    {
+                      "tailTransactionHash": "0x00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000",
+                      "address": {
+                        "type": 0,
+                        "pubKeyHash": "0x4ecd2c3e8b8a4d2d1b57b8cda27bd2c9c1aa07b94e1e4c2ba3c3a0fa8a6c7a2d"
+                      },
+                      "deposit": "3000000"
+                    }
+                  ],
+                  "transaction": {
+                    "type": 4,
+                    "input": {
+                      "type": 1,
+                      "milestoneId": "0x2b1e6b9f0c4d8a7e5f3c2d1b0a9e8f7c6d5b4a3928170f6e5d4c3b2a1908f7e6"
+                    },
+                    "output": {
+                      "type": 2,
+                      "amount": "144000000000000"
+                    }
+                  }
+                },
+                "milestoneIndex": 470
+              }
+            ]
+          }"#
+        .as_slice(),
+        "https://example.iota.org/api/v2/treasury" => br#"{
+            "milestoneId": "0x7a09324557e9200f39bf493fc8fd6ac43e9ca750c6f6d884cc72386ddcb7d695",
+            "amount": "144000000000000"
+          }"#
+        .as_slice(),
+        "https://example.iota.org/api/v2/peers" => br#"[
+            {
+              "id": "12D3KooWRMquyuA1FNvkzLzGqLbhs4sRkqnZqG2DPETaBEdGhaYz",
+              "multiAddresses": [
+                "/ip4/192.0.2.1/tcp/15600"
+              ],
+              "alias": "hornet-1",
+              "relation": "known",
+              "connected": true,
+              "gossip": {
+                "heartbeat": {
+                  "solidMilestoneIndex": 480,
+                  "prunedMilestoneIndex": 0,
+                  "latestMilestoneIndex": 480,
+                  "connectedPeers": 4,
+                  "syncedPeers": 4
+                },
+                "metrics": {
+                  "newBlocks": 1200,
+                  "knownBlocks": 3400,
+                  "receivedBlocks": 4600,
+                  "receivedBlockRequests": 30,
+                  "receivedMilestoneRequests": 2,
+                  "receivedHeartbeats": 120,
+                  "sentBlocks": 4500,
+                  "sentBlockRequests": 25,
+                  "sentMilestoneRequests": 1,
+                  "sentHeartbeats": 118,
+                  "droppedPackets": 0
+                }
+              }
+            }
+          ]"#
+        .as_slice(),
+        "https://example.iota.org/api/v2/peers/12D3KooWRMquyuA1FNvkzLzGqLbhs4sRkqnZqG2DPETaBEdGhaYz" => {
+            br#"{
+              "id": "12D3KooWRMquyuA1FNvkzLzGqLbhs4sRkqnZqG2DPETaBEdGhaYz",
+              "multiAddresses": [
+                "/ip4/192.0.2.1/tcp/15600"
+              ],
+              "alias": "hornet-1",
+              "relation": "known",
+              "connected": true,
+              "gossip": {
+                "heartbeat": {
+                  "solidMilestoneIndex": 480,
+                  "prunedMilestoneIndex": 0,
+                  "latestMilestoneIndex": 480,
+                  "connectedPeers": 4,
+                  "syncedPeers": 4
+                },
+                "metrics": {
+                  "newBlocks": 1200,
+                  "knownBlocks": 3400,
+                  "receivedBlocks": 4600,
+                  "receivedBlockRequests": 30,
+                  "receivedMilestoneRequests": 2,
+                  "receivedHeartbeats": 120,
+                  "sentBlocks": 4500,
+                  "sentBlockRequests": 25,
+                  "sentMilestoneRequests": 1,
+                  "sentHeartbeats": 118,
+                  "droppedPackets": 0
+                }
+              }
+            }"#
+                .as_slice()
+        }
+        "https://chrysalis.example.iota.org/health" => b"".as_slice(),
+        "https://chrysalis.example.iota.org/api/v1/info" => br#"{
+            "data": {
+              "name": "HORNET",
+              "version": "1.2.0",
+              "isHealthy": true,
+              "networkId": "chrysalis-mainnet",
+              "bech32HRP": "iota",
+              "minPoWScore": 4000,
+              "messagesPerSecond": 12.3,
+              "referencedMessagesPerSecond": 11.9,
+              "referencedRate": 96.7479674796748,
+              "latestMilestoneTimestamp": 1651234567,
+              "latestMilestoneIndex": 3245678,
+              "confirmedMilestoneIndex": 3245678,
+              "pruningIndex": 3200000,
+              "features": []
+            }
+          }"#
+        .as_slice(),
+        "https://chrysalis.example.iota.org/api/v1/messages/\
+         9b6d8b6f7e2dcf9c1b4a4b08e3e8f1d1f0f6a0d3b0d5e5f7e5b0c8a6f1b2c3d4" => br#"{
+            "data": {
+              "networkId": "14379272398717627559",
+              "parentMessageIds": [
+                "7dd3b0b9c9e0a7b7c4d3e4f5a6b7c8d9e0f1a2b3c4d5e6f7a8b9c0d1e2f3a4b5"
+              ],
+              "payload": {
+                "type": 2,
+                "index": "61746f69",
+                "data": "68656c6c6f"
+              },
+              "nonce": "10760600709663927622"
+            }
+          }"#
+        .as_slice(),
+        "https://chrysalis.example.iota.org/api/v1/messages?index=61746f69" => br#"{
+            "data": {
+              "index": "61746f69",
+              "maxResults": 1000,
+              "count": 1,
+              "messageIds": [
+                "9b6d8b6f7e2dcf9c1b4a4b08e3e8f1d1f0f6a0d3b0d5e5f7e5b0c8a6f1b2c3d4"
+              ]
+            }
+          }"#
+        .as_slice(),
+        _ => b"".as_slice(),
+    }
+    .to_vec()
+}
+
+/// Responses to `POST` requests, where they differ from those to `GET` requests.
+pub(super) fn respond_dummy_post(url: &str) -> Vec<u8> {
+    match url {
+        "https://example.iota.org/api/v2/peers" => respond_dummy(
+            "https://example.iota.org/api/v2/peers/12D3KooWRMquyuA1FNvkzLzGqLbhs4sRkqnZqG2DPETaBEdGhaYz",
+            MediaType::Json,
+        ),
+        "https://example.iota.org/api/v2/control/database/prune" => br#"{
+            "index": 380
+          }"#
+        .to_vec(),
+        "https://example.iota.org/api/v2/control/snapshots/create" => br#"{
+            "index": 470,
+            "filePath": "snapshots/full_snapshot_470.bin"
+          }"#
+        .to_vec(),
+        _ => respond_dummy(url, MediaType::Json),
+    }
+}
+
+fn respond_dummy_binary(url: &str) -> Vec<u8> {
+    match url {
+        "https://example.iota.org/api/v2/blocks/\
+         0x9a1a0b1f81a4c25d9d6d0e8c4e6a2d8d4e0f5c2b1a3e7d6c5b4a39281706f5e4" => {
+            b"\x02\x01\x3b\x0c\x7e\x9f".as_slice()
+        }
         _ => b"".as_slice(),
     }
     .to_vec()
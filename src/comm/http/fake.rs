@@ -0,0 +1,248 @@
+//! A scriptable HTTP client for unit tests.
+
+use super::{AsyncHttpClient, DummyHttpClient, HttpClient};
+use crate::api::{MediaType, Method, Response};
+use crate::types::{Auth, CancellationToken, Error, Result};
+use alloc::collections::VecDeque;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::{boxed::Box, vec::Vec};
+use async_trait::async_trait;
+use spin::Mutex;
+
+/// The scripted answers to a method and URL.
+type Script = (Method, String, VecDeque<Result<(u16, Vec<u8>)>>);
+
+/// An HTTP client answering requests with scripted status codes, bodies and errors, like
+/// [`CurlHttpClient`](super::CurlHttpClient) would report them.
+///
+/// Responses are scripted per method and URL, and consumed in order; the last one is repeated.
+/// Requests without a scripted response get `404 Not Found`.
+#[derive(Default)]
+pub(crate) struct FakeHttpClient {
+    scripts: Mutex<Vec<Script>>,
+    requests: Mutex<Vec<(Method, String)>>,
+    /// Requests never answered by the asynchronous interface, until cancelled.
+    hanging: Mutex<Vec<(Method, String)>>,
+}
+
+impl FakeHttpClient {
+    pub(crate) fn new() -> Self {
+        Default::default()
+    }
+
+    /// Answer the next `method` request to `url` with `status` and `body`.
+    pub(crate) fn respond(&self, method: Method, url: &str, status: u16, body: &str) -> &Self {
+        self.script(method, url, Ok((status, body.as_bytes().to_vec())))
+    }
+
+    /// Answer the discovery of the node at `base` like a Stardust node offering `routes`, e.g.
+    /// `core/v2`.
+    pub(crate) fn node(&self, base: &str, routes: &[&str]) -> &Self {
+        let info = HttpClient::get(
+            &DummyHttpClient::new(),
+            "https://example.iota.org/api/v2/info",
+            None,
+            MediaType::Json,
+        )
+        .expect("dummy info");
+        let mut info: serde_json::Value = serde_json::from_slice(&info.body).expect("dummy info");
+        info["plugins"] = serde_json::json!([]);
+        info["features"] = serde_json::json!([]);
+
+        self.respond(
+            Method::Get,
+            &format!("{}/api/v2/info", base),
+            200,
+            &info.to_string(),
+        )
+        .respond(
+            Method::Get,
+            &format!("{}/api/routes", base),
+            200,
+            &serde_json::json!({ "routes": routes }).to_string(),
+        )
+    }
+
+    /// Answer the discovery of the node at `base` like a Chrysalis node, which only offers the
+    /// v1 info.
+    #[cfg_attr(not(feature = "sync"), allow(dead_code))]
+    pub(crate) fn chrysalis_node(&self, base: &str) -> &Self {
+        let info = HttpClient::get(
+            &DummyHttpClient::new(),
+            "https://chrysalis.example.iota.org/api/v1/info",
+            None,
+            MediaType::Json,
+        )
+        .expect("dummy info");
+
+        self.respond(
+            Method::Get,
+            &format!("{}/api/v1/info", base),
+            200,
+            core::str::from_utf8(&info.body).expect("dummy info"),
+        )
+    }
+
+    /// Never answer `method` requests to `url` on the asynchronous interface, until they are
+    /// cancelled.
+    #[cfg_attr(not(all(feature = "std", feature = "async")), allow(dead_code))]
+    pub(crate) fn hang(&self, method: Method, url: &str) -> &Self {
+        self.hanging.lock().push((method, url.to_string()));
+        self
+    }
+
+    /// Fail the next `method` request to `url` with `error`, e.g. a transport error.
+    #[cfg_attr(not(feature = "sync"), allow(dead_code))]
+    pub(crate) fn fail(&self, method: Method, url: &str, error: Error) -> &Self {
+        self.script(method, url, Err(error))
+    }
+
+    /// The requests sent so far, in order.
+    pub(crate) fn requests(&self) -> Vec<(Method, String)> {
+        self.requests.lock().clone()
+    }
+
+    /// The number of requests sent so far to `url`.
+    #[cfg_attr(
+        not(any(feature = "sync", all(feature = "std", feature = "async"))),
+        allow(dead_code)
+    )]
+    pub(crate) fn count(&self, url: &str) -> usize {
+        self.requests
+            .lock()
+            .iter()
+            .filter(|(_, sent)| sent == url)
+            .count()
+    }
+
+    fn script(&self, method: Method, url: &str, result: Result<(u16, Vec<u8>)>) -> &Self {
+        let mut scripts = self.scripts.lock();
+
+        match scripts
+            .iter_mut()
+            .find(|(scripted, scripted_url, _)| *scripted == method && scripted_url == url)
+        {
+            Some((_, _, results)) => results.push_back(result),
+            None => scripts.push((method, url.to_string(), VecDeque::from([result]))),
+        }
+        drop(scripts);
+
+        self
+    }
+
+    fn answer(&self, method: Method, url: &str) -> Result<Response> {
+        #[cfg(feature = "tracing")]
+        tracing::trace!(url, "fake request answered");
+
+        self.requests.lock().push((method, url.to_string()));
+
+        let scripted = self
+            .scripts
+            .lock()
+            .iter_mut()
+            .find(|(scripted, scripted_url, _)| *scripted == method && scripted_url == url)
+            .and_then(|(_, _, results)| match results.len() {
+                0 => None,
+                1 => results.front().cloned(),
+                _ => results.pop_front(),
+            });
+
+        match scripted {
+            Some(Ok((429, _))) => Err(Error::RateLimited {
+                url: url.into(),
+                retry_after: None,
+            }),
+            Some(Ok((code @ 400..=599, body))) => Err(Error::from_response(url, code, &body)),
+            Some(Ok((_, body))) => Ok(body.into()),
+            Some(Err(err)) => Err(err),
+            None => Err(Error::from_response(
+                url,
+                404,
+                br#"{"error":{"code":"404","message":"not found"}}"#,
+            )),
+        }
+    }
+}
+
+impl FakeHttpClient {
+    /// Wait for `cancel` if requests to `url` hang, failing if cancelled.
+    async fn unless_hanging(
+        &self,
+        method: Method,
+        url: &str,
+        cancel: &CancellationToken,
+    ) -> Result<()> {
+        let hanging = self
+            .hanging
+            .lock()
+            .iter()
+            .any(|(hanging, hanging_url)| *hanging == method && hanging_url == url);
+
+        if hanging {
+            self.requests.lock().push((method, url.to_string()));
+            cancel.cancelled().await;
+        }
+
+        match cancel.is_cancelled() {
+            true => Err(Error::Cancelled),
+            false => Ok(()),
+        }
+    }
+}
+
+impl HttpClient for FakeHttpClient {
+    fn get(&self, url: &str, _auth: Option<&Auth>, _accept: MediaType) -> Result<Response> {
+        self.answer(Method::Get, url)
+    }
+
+    fn post(
+        &self,
+        url: &str,
+        _auth: Option<&Auth>,
+        _body: &[u8],
+        _content_type: MediaType,
+    ) -> Result<Response> {
+        self.answer(Method::Post, url)
+    }
+
+    fn delete(&self, url: &str, _auth: Option<&Auth>) -> Result<Response> {
+        self.answer(Method::Delete, url)
+    }
+}
+
+#[async_trait]
+impl AsyncHttpClient for FakeHttpClient {
+    async fn get(
+        &self,
+        url: &str,
+        auth: Option<&Auth>,
+        accept: MediaType,
+        cancel: &CancellationToken,
+    ) -> Result<Response> {
+        self.unless_hanging(Method::Get, url, cancel).await?;
+        HttpClient::get(self, url, auth, accept)
+    }
+
+    async fn post(
+        &self,
+        url: &str,
+        auth: Option<&Auth>,
+        body: &[u8],
+        content_type: MediaType,
+        cancel: &CancellationToken,
+    ) -> Result<Response> {
+        self.unless_hanging(Method::Post, url, cancel).await?;
+        HttpClient::post(self, url, auth, body, content_type)
+    }
+
+    async fn delete(
+        &self,
+        url: &str,
+        auth: Option<&Auth>,
+        cancel: &CancellationToken,
+    ) -> Result<Response> {
+        self.unless_hanging(Method::Delete, url, cancel).await?;
+        HttpClient::delete(self, url, auth)
+    }
+}
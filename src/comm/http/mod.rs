@@ -3,26 +3,64 @@
 #[cfg(feature = "curl")]
 mod curl;
 mod dummy;
+#[cfg(all(test, any(feature = "sync", feature = "async")))]
+pub(crate) mod fake;
 
 #[cfg(feature = "curl")]
 pub use self::curl::CurlHttpClient;
 pub use self::dummy::DummyHttpClient;
 
-use crate::types::{Auth, Result};
-use alloc::{boxed::Box, vec::Vec};
+use crate::api::{MediaType, Response};
+use crate::types::{Auth, CancellationToken, Result};
+use alloc::boxed::Box;
 use async_trait::async_trait;
 
 /// Interfaces of a synchronous HTTP client.
-pub trait HttpClient {
-    fn get(&self, url: &str, auth: Option<&Auth>) -> Result<Vec<u8>>;
-    fn post(&self, url: &str, auth: Option<&Auth>, body: &[u8]) -> Result<Vec<u8>>;
-    fn delete(&self, url: &str, auth: Option<&Auth>) -> Result<Vec<u8>>;
+///
+/// `GET` requests ask for a response of the `accept` media type, and `POST` requests send a body
+/// of the `content_type` media type. Responses with an error status are returned as
+/// [`Error::NodeError`](crate::types::Error::NodeError) (see
+/// [`Error::from_response`](crate::types::Error::from_response)). Successful responses keep the
+/// `Location` header, if any.
+pub trait HttpClient: Send + Sync {
+    fn get(&self, url: &str, auth: Option<&Auth>, accept: MediaType) -> Result<Response>;
+    fn post(
+        &self,
+        url: &str,
+        auth: Option<&Auth>,
+        body: &[u8],
+        content_type: MediaType,
+    ) -> Result<Response>;
+    fn delete(&self, url: &str, auth: Option<&Auth>) -> Result<Response>;
 }
 
-/// Interfaces of an asynchronous HTTP client.
+/// Interfaces of an asynchronous HTTP client, like [`HttpClient`].
+///
+/// Implementations should stop the transfer and return [`Error::Cancelled`] as soon as possible
+/// once `cancel` is cancelled, since the result will not be awaited any more.
+///
+/// [`Error::Cancelled`]: crate::types::Error::Cancelled
 #[async_trait]
-pub trait AsyncHttpClient {
-    async fn get(&self, url: &str, auth: Option<&Auth>) -> Result<Vec<u8>>;
-    async fn post(&self, url: &str, auth: Option<&Auth>, body: &[u8]) -> Result<Vec<u8>>;
-    async fn delete(&self, url: &str, auth: Option<&Auth>) -> Result<Vec<u8>>;
+pub trait AsyncHttpClient: Send + Sync {
+    async fn get(
+        &self,
+        url: &str,
+        auth: Option<&Auth>,
+        accept: MediaType,
+        cancel: &CancellationToken,
+    ) -> Result<Response>;
+    async fn post(
+        &self,
+        url: &str,
+        auth: Option<&Auth>,
+        body: &[u8],
+        content_type: MediaType,
+        cancel: &CancellationToken,
+    ) -> Result<Response>;
+    async fn delete(
+        &self,
+        url: &str,
+        auth: Option<&Auth>,
+        cancel: &CancellationToken,
+    ) -> Result<Response>;
 }
@@ -1,8 +1,9 @@
 //! Communication backends for [clients] to connect to the node software.
 //!
 //! These are pluggable and reusable modules wrapping specific libraries that perform network
-//! requests.
+//! requests, or keep time.
 //!
 //! [clients]: crate::client
 
 pub mod http;
+pub mod timer;
@@ -0,0 +1,210 @@
+//! Timers used to enforce deadlines, independent of any asynchronous runtime.
+
+use alloc::boxed::Box;
+use core::future::Future;
+use core::pin::Pin;
+use core::time::Duration;
+
+/// A source of asynchronous sleeps.
+///
+/// Any function returning a future can serve as a timer, so the timer of the runtime in use can be
+/// plugged in directly, e.g. `async_std::task::sleep`.
+pub trait Timer: Send + Sync {
+    /// Returns a future that completes after `duration`.
+    fn sleep(&self, duration: Duration) -> Pin<Box<dyn Future<Output = ()> + Send>>;
+}
+
+impl<F, S> Timer for F
+where
+    F: Fn(Duration) -> S + Send + Sync,
+    S: Future<Output = ()> + Send + 'static,
+{
+    fn sleep(&self, duration: Duration) -> Pin<Box<dyn Future<Output = ()> + Send>> {
+        Box::pin(self(duration))
+    }
+}
+
+/// A timer that sleeps on a separate thread, for use without an asynchronous runtime.
+///
+/// All sleeps share a single thread, started on the first sleep.
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct ThreadTimer;
+
+#[cfg(feature = "std")]
+impl Timer for ThreadTimer {
+    fn sleep(&self, duration: Duration) -> Pin<Box<dyn Future<Output = ()> + Send>> {
+        Box::pin(thread::ThreadSleep::new(duration))
+    }
+}
+
+#[cfg(feature = "std")]
+mod thread {
+    use alloc::collections::BTreeMap;
+    use core::future::Future;
+    use core::pin::Pin;
+    use core::task::{Context, Poll};
+    use core::time::Duration;
+    use std::sync::{Arc, Condvar, Mutex};
+    use std::task::Waker;
+    use std::time::Instant;
+
+    static QUEUE: spin::Once<Arc<Queue>> = spin::Once::new();
+
+    /// The state of a sleep, shared between its future and the timer thread.
+    #[derive(Default)]
+    struct Sleep {
+        elapsed: bool,
+        waker: Option<Waker>,
+    }
+
+    /// A sleep on the timer thread, removed from its queue once dropped.
+    pub(super) struct ThreadSleep {
+        key: (Instant, u64),
+        sleep: Arc<Mutex<Sleep>>,
+    }
+
+    impl ThreadSleep {
+        pub(super) fn new(duration: Duration) -> Self {
+            let sleep = Arc::new(Mutex::new(Sleep::default()));
+            let key = queue().push(Instant::now() + duration, sleep.clone());
+
+            Self { key, sleep }
+        }
+    }
+
+    impl Future for ThreadSleep {
+        type Output = ();
+
+        fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+            let mut sleep = self.sleep.lock().unwrap();
+
+            if sleep.elapsed {
+                Poll::Ready(())
+            } else {
+                sleep.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+
+    impl Drop for ThreadSleep {
+        fn drop(&mut self) {
+            queue().remove(self.key);
+        }
+    }
+
+    /// Pending sleeps by deadline, and by order of arrival among equal deadlines.
+    #[derive(Default)]
+    struct Pending {
+        sleeps: BTreeMap<(Instant, u64), Arc<Mutex<Sleep>>>,
+        next_id: u64,
+    }
+
+    #[derive(Default)]
+    struct Queue {
+        pending: Mutex<Pending>,
+        changed: Condvar,
+    }
+
+    /// The queue of the timer thread, starting the thread on first use.
+    fn queue() -> &'static Queue {
+        QUEUE.call_once(|| {
+            let queue = Arc::new(Queue::default());
+            let thread_queue = queue.clone();
+
+            std::thread::Builder::new()
+                .name("atoi-timer".into())
+                .spawn(move || thread_queue.run())
+                .expect("failed to spawn the timer thread");
+
+            queue
+        })
+    }
+
+    impl Queue {
+        /// Queue `sleep` until `deadline`, returning its key in the queue.
+        fn push(&self, deadline: Instant, sleep: Arc<Mutex<Sleep>>) -> (Instant, u64) {
+            let mut pending = self.pending.lock().unwrap();
+            let key = (deadline, pending.next_id);
+
+            pending.sleeps.insert(key, sleep);
+            pending.next_id += 1;
+            drop(pending);
+
+            self.changed.notify_one();
+            key
+        }
+
+        /// Forget the sleep at `key`, elapsed or not.
+        fn remove(&self, key: (Instant, u64)) {
+            self.pending.lock().unwrap().sleeps.remove(&key);
+        }
+
+        fn run(&self) {
+            let mut pending = self.pending.lock().unwrap();
+
+            loop {
+                let now = Instant::now();
+
+                while let Some(&key) = pending.sleeps.keys().next().filter(|(at, _)| *at <= now) {
+                    if let Some(sleep) = pending.sleeps.remove(&key) {
+                        let mut sleep = sleep.lock().unwrap();
+                        sleep.elapsed = true;
+                        if let Some(waker) = sleep.waker.take() {
+                            waker.wake();
+                        }
+                    }
+                }
+
+                pending = match pending.sleeps.keys().next() {
+                    Some(&(deadline, _)) => {
+                        self.changed
+                            .wait_timeout(pending, deadline.saturating_duration_since(now))
+                            .unwrap()
+                            .0
+                    }
+                    None => self.changed.wait(pending).unwrap(),
+                };
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn dropped_sleeps_leave_the_queue() {
+            let sleep = ThreadSleep::new(Duration::from_secs(3600));
+            let key = sleep.key;
+            assert!(queue().pending.lock().unwrap().sleeps.contains_key(&key));
+
+            drop(sleep);
+
+            assert!(!queue().pending.lock().unwrap().sleeps.contains_key(&key));
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::{ThreadTimer, Timer};
+    use core::time::Duration;
+    use std::time::Instant;
+
+    #[async_std::test]
+    async fn thread_timer_sleeps_for_their_durations() {
+        let start = Instant::now();
+        let long = ThreadTimer.sleep(Duration::from_millis(40));
+        let short = ThreadTimer.sleep(Duration::from_millis(10));
+
+        short.await;
+        let short_elapsed = start.elapsed();
+        long.await;
+        let long_elapsed = start.elapsed();
+
+        assert!(short_elapsed >= Duration::from_millis(10));
+        assert!(long_elapsed >= Duration::from_millis(40));
+    }
+}
@@ -15,6 +15,7 @@
 //! - [TIP-26 UTXO Indexer REST API](https://github.com/iotaledger/tips/pull/62)
 //! - [TIP-16 Event MQTT API](https://github.com/iotaledger/tips/blob/main/tips/TIP-0016/tip-0016.md)
 //! - [Hornet Plugin - Faucet REST APT](#)
+//! - [Hornet Plugin - Participation REST API](https://github.com/iotaledger/inx-participation)
 //!
 //! ## Features
 //!
@@ -23,6 +24,9 @@
 //!
 //! - `sync`: turns on `Client` with synchronous interfaces.
 //! - `async`: turns on `AsyncClient` with asynchronous interfaces.
+//! - `toml`: allows reading `Config` from TOML files.
+//! - `tracing`: emits [`tracing`](https://docs.rs/tracing) spans and events for every call.
+//! - `metrics`: allows collecting counters of requests with a `metrics::Recorder`.
 //! - `tls-webpki`: bundle the Web PKI CA certificates and use it for TLS connections.
 //! - `tls-native`: use the CA certificates available on the running system.
 //!
@@ -34,10 +38,14 @@
 
 extern crate alloc;
 
+pub mod api;
+pub mod cache;
 pub mod client;
 pub mod comm;
 #[cfg(feature = "ffi")]
 pub mod ffi;
+#[cfg(feature = "metrics")]
+pub mod metrics;
 pub mod types;
 
 // Re-exports.
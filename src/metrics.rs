@@ -0,0 +1,26 @@
+//! Hooks to collect metrics of the requests made by clients.
+//!
+//! Implement [`Recorder`] to feed the counters into the metrics system in use, and give it to a
+//! client. All methods have empty default implementations, so only the interesting ones need to be
+//! implemented.
+
+use crate::types::Error;
+
+/// Receiver of metrics events. `endpoint` is the [name](crate::api::Endpoint::name) of the endpoint
+/// being called, and `node` the URL of the node a request is sent to.
+pub trait Recorder: Send + Sync {
+    /// A request has been sent.
+    fn request(&self, _endpoint: &str, _node: &str) {}
+
+    /// A request has been sent again after a failure.
+    fn retry(&self, _endpoint: &str, _node: &str) {}
+
+    /// A request has failed.
+    fn error(&self, _endpoint: &str, _node: &str, _error: &Error) {}
+
+    /// A request body has been sent.
+    fn bytes_sent(&self, _endpoint: &str, _node: &str, _bytes: usize) {}
+
+    /// A response body has been received.
+    fn bytes_received(&self, _endpoint: &str, _node: &str, _bytes: usize) {}
+}
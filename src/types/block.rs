@@ -0,0 +1,316 @@
+//! Blocks, the unit of data in the Stardust Tangle.
+
+use super::error::Error;
+use super::{BlockId, MilestonePayload, Parents, Result};
+use alloc::{boxed::Box, string::String, vec::Vec};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+
+/// A block, in its JSON form.
+///
+/// ```
+/// use atoi::comm::http::DummyHttpClient;
+/// use atoi::types::{Block, Node, Payload, TaggedDataPayload};
+/// use atoi::Client;
+/// use std::sync::Arc;
+///
+/// let client = Client::builder()
+///     .nodes(vec!["https://example.iota.org".parse::<Node>().unwrap()])
+///     .http(Arc::new(DummyHttpClient::new()))
+///     .build();
+///
+/// let payload = Payload::TaggedData(TaggedDataPayload::new(b"atoi", b"hello"));
+/// let block = Block::new(client.parents().unwrap(), Some(payload));
+///
+/// let block_id = client.submit_block(block).unwrap();
+/// assert_eq!(
+///     block_id.to_string(),
+///     "0x9a1a0b1f81a4c25d9d6d0e8c4e6a2d8d4e0f5c2b1a3e7d6c5b4a39281706f5e4"
+/// );
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Block {
+    pub protocol_version: u8,
+    /// The IDs of the parents, sorted and without duplicates.
+    pub parents: Vec<BlockId>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payload: Option<Payload>,
+    /// The nonce of the proof of work, as a decimal string. Nodes offering
+    /// [`Capability::PoW`](super::Capability::PoW) do the proof of work for blocks submitted with
+    /// a zero nonce.
+    pub nonce: String,
+}
+
+impl Block {
+    /// The protocol version of Stardust blocks.
+    pub const PROTOCOL_VERSION: u8 = 2;
+
+    /// A block with `payload` attached to `parents`, whose proof of work is left to the node.
+    pub fn new(parents: Parents, payload: Option<Payload>) -> Self {
+        Self {
+            protocol_version: Self::PROTOCOL_VERSION,
+            parents: parents.into(),
+            payload,
+            nonce: "0".into(),
+        }
+    }
+
+    /// Check the constraints on blocks that can be checked without the ledger.
+    pub fn validate(&self) -> Result<()> {
+        if !(Parents::MIN..=Parents::MAX).contains(&self.parents.len()) {
+            return Err(Error::InvalidBlock(alloc::format!(
+                "{} parents instead of {} to {}",
+                self.parents.len(),
+                Parents::MIN,
+                Parents::MAX
+            )));
+        }
+        if self.parents.windows(2).any(|pair| pair[0] >= pair[1]) {
+            return Err(Error::InvalidBlock(
+                "parents are not sorted or not unique".into(),
+            ));
+        }
+        if self.nonce.parse::<u64>().is_err() {
+            return Err(Error::InvalidBlock(alloc::format!(
+                "invalid nonce `{}`",
+                self.nonce
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+/// The payload of a [`Block`].
+///
+/// Only tagged data and milestone payloads are typed; other payloads (transactions, ...) are
+/// kept as raw JSON.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Payload {
+    TaggedData(TaggedDataPayload),
+    Milestone(Box<MilestonePayload>),
+    Other(Value),
+}
+
+impl Payload {
+    /// The type of tagged data payloads.
+    pub const TAGGED_DATA_TYPE: u64 = 5;
+    /// The type of milestone payloads.
+    pub const MILESTONE_TYPE: u64 = 7;
+
+    /// The type of the payload.
+    pub fn kind(&self) -> Option<u64> {
+        match self {
+            Self::TaggedData(_) => Some(Self::TAGGED_DATA_TYPE),
+            Self::Milestone(_) => Some(Self::MILESTONE_TYPE),
+            Self::Other(value) => value.get("type").and_then(Value::as_u64),
+        }
+    }
+}
+
+impl Serialize for Payload {
+    fn serialize<S: Serializer>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error> {
+        match self {
+            Self::TaggedData(tagged_data) => serde_json::json!({
+                "type": Self::TAGGED_DATA_TYPE,
+                "tag": tagged_data.tag,
+                "data": tagged_data.data,
+            })
+            .serialize(serializer),
+            Self::Milestone(milestone) => milestone.serialize(serializer),
+            Self::Other(value) => value.serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Payload {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> core::result::Result<Self, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+
+        match value.get("type").and_then(Value::as_u64) {
+            Some(Self::TAGGED_DATA_TYPE) => TaggedDataPayload::deserialize(value)
+                .map(Self::TaggedData)
+                .map_err(serde::de::Error::custom),
+            Some(Self::MILESTONE_TYPE) => MilestonePayload::deserialize(value)
+                .map(|milestone| Self::Milestone(Box::new(milestone)))
+                .map_err(serde::de::Error::custom),
+            _ => Ok(Self::Other(value)),
+        }
+    }
+}
+
+/// A payload attaching arbitrary data to a block, with a tag.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+pub struct TaggedDataPayload {
+    /// The `0x`-prefixed hex-encoded tag.
+    #[serde(default)]
+    pub tag: String,
+    /// The `0x`-prefixed hex-encoded data.
+    #[serde(default)]
+    pub data: String,
+}
+
+impl TaggedDataPayload {
+    /// A tagged data payload of raw `tag` and `data`.
+    pub fn new(tag: &[u8], data: &[u8]) -> Self {
+        Self {
+            tag: alloc::format!("0x{}", hex::encode(tag)),
+            data: alloc::format!("0x{}", hex::encode(data)),
+        }
+    }
+
+    /// The decoded tag.
+    pub fn tag_bytes(&self) -> Result<Vec<u8>> {
+        decode_prefixed(&self.tag)
+    }
+
+    /// The decoded data.
+    pub fn data_bytes(&self) -> Result<Vec<u8>> {
+        decode_prefixed(&self.data)
+    }
+}
+
+fn decode_prefixed(hex: &str) -> Result<Vec<u8>> {
+    hex::decode(hex.strip_prefix("0x").unwrap_or(hex))
+        .map_err(|err| Error::ResponseError(alloc::format!("`{}`: {}", hex, err)))
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubmitBlockResponse {
+    pub block_id: BlockId,
+}
+
+/// The metadata of a block: its state in the Tangle and in the ledger.
+///
+/// ```
+/// use atoi::comm::http::DummyHttpClient;
+/// use atoi::types::{BlockId, LedgerInclusionState, Node};
+/// use atoi::Client;
+/// use std::sync::Arc;
+///
+/// let client = Client::builder()
+///     .nodes(vec!["https://example.iota.org".parse::<Node>().unwrap()])
+///     .http(Arc::new(DummyHttpClient::new()))
+///     .build();
+/// let block_id: BlockId = "0x9a1a0b1f81a4c25d9d6d0e8c4e6a2d8d4e0f5c2b1a3e7d6c5b4a39281706f5e4"
+///     .parse()
+///     .unwrap();
+///
+/// let metadata = client.get_block_metadata(block_id).unwrap();
+/// assert!(metadata.is_referenced());
+/// assert_eq!(
+///     metadata.ledger_inclusion_state,
+///     Some(LedgerInclusionState::NoTransaction)
+/// );
+///
+/// let block = client.get_block(block_id).unwrap();
+/// assert_eq!(block.parents, metadata.parents);
+/// assert_eq!(client.get_block_raw(block_id).unwrap()[0], 2);
+/// ```
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockMetadata {
+    pub block_id: BlockId,
+    pub parents: Vec<BlockId>,
+    pub is_solid: bool,
+    /// The index of the milestone referencing the block, once referenced.
+    pub referenced_by_milestone_index: Option<u32>,
+    /// The index of the milestone, if the block contains one.
+    pub milestone_index: Option<u32>,
+    /// Whether the payload of the block changed the ledger, once referenced.
+    pub ledger_inclusion_state: Option<LedgerInclusionState>,
+    /// Why the transaction of the block was not applied, if it is conflicting.
+    pub conflict_reason: Option<ConflictReason>,
+    pub white_flag_index: Option<u32>,
+    /// Whether the block should be promoted to get referenced sooner.
+    pub should_promote: Option<bool>,
+    /// Whether the block can no longer get referenced, and should be reattached.
+    pub should_reattach: Option<bool>,
+}
+
+impl BlockMetadata {
+    /// Whether a milestone references the block.
+    pub fn is_referenced(&self) -> bool {
+        self.referenced_by_milestone_index.is_some()
+    }
+}
+
+/// Whether the payload of a referenced block changed the ledger.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum LedgerInclusionState {
+    /// The block has no transaction.
+    NoTransaction,
+    /// The transaction of the block was applied to the ledger.
+    Included,
+    /// The transaction of the block conflicts with the ledger, and was not applied.
+    Conflicting,
+}
+
+/// Why the transaction of a block conflicts with the ledger.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+#[serde(from = "u8", into = "u8")]
+pub enum ConflictReason {
+    None,
+    InputAlreadySpent,
+    InputSpentInSameMilestone,
+    InputNotFound,
+    AmountMismatch,
+    InvalidSignature,
+    TimelockNotExpired,
+    InvalidNativeTokens,
+    StorageDepositReturnUnfulfilled,
+    InvalidUnlock,
+    InputsCommitmentMismatch,
+    InvalidSender,
+    InvalidChainStateTransition,
+    SemanticValidationFailed,
+    /// A reason unknown to this library.
+    Other(u8),
+}
+
+impl From<u8> for ConflictReason {
+    fn from(code: u8) -> Self {
+        match code {
+            0 => Self::None,
+            1 => Self::InputAlreadySpent,
+            2 => Self::InputSpentInSameMilestone,
+            3 => Self::InputNotFound,
+            4 => Self::AmountMismatch,
+            5 => Self::InvalidSignature,
+            6 => Self::TimelockNotExpired,
+            7 => Self::InvalidNativeTokens,
+            8 => Self::StorageDepositReturnUnfulfilled,
+            9 => Self::InvalidUnlock,
+            10 => Self::InputsCommitmentMismatch,
+            11 => Self::InvalidSender,
+            12 => Self::InvalidChainStateTransition,
+            255 => Self::SemanticValidationFailed,
+            code => Self::Other(code),
+        }
+    }
+}
+
+impl From<ConflictReason> for u8 {
+    fn from(reason: ConflictReason) -> u8 {
+        match reason {
+            ConflictReason::None => 0,
+            ConflictReason::InputAlreadySpent => 1,
+            ConflictReason::InputSpentInSameMilestone => 2,
+            ConflictReason::InputNotFound => 3,
+            ConflictReason::AmountMismatch => 4,
+            ConflictReason::InvalidSignature => 5,
+            ConflictReason::TimelockNotExpired => 6,
+            ConflictReason::InvalidNativeTokens => 7,
+            ConflictReason::StorageDepositReturnUnfulfilled => 8,
+            ConflictReason::InvalidUnlock => 9,
+            ConflictReason::InputsCommitmentMismatch => 10,
+            ConflictReason::InvalidSender => 11,
+            ConflictReason::InvalidChainStateTransition => 12,
+            ConflictReason::SemanticValidationFailed => 255,
+            ConflictReason::Other(code) => code,
+        }
+    }
+}
@@ -0,0 +1,189 @@
+//! Cancellation of asynchronous work.
+
+use alloc::collections::BTreeMap;
+use alloc::sync::Arc;
+use core::future::Future;
+use core::pin::Pin;
+use core::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use core::task::{Context, Poll, Waker};
+
+/// A token signalling that some asynchronous work should be cancelled.
+///
+/// Tokens are cheap to clone; all clones refer to the same cancellation state. A [child
+/// token](Self::child_token) is cancelled together with its parent, but cancelling it leaves the
+/// parent untouched.
+///
+/// ```
+/// use atoi::types::CancellationToken;
+///
+/// let token = CancellationToken::new();
+/// let child = token.child_token();
+///
+/// child.cancel();
+/// assert!(child.is_cancelled());
+/// assert!(!token.is_cancelled());
+///
+/// token.cancel();
+/// assert!(token.child_token().is_cancelled());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken(Arc<Inner>);
+
+#[derive(Debug, Default)]
+struct Inner {
+    cancelled: AtomicBool,
+    parent: Option<CancellationToken>,
+    /// Wakers of the pending [`Cancelled`] futures of this token and its children, by the key of
+    /// the future.
+    wakers: spin::Mutex<BTreeMap<usize, Waker>>,
+}
+
+/// The key of the next [`Cancelled`] future to register a waker.
+static NEXT_KEY: AtomicUsize = AtomicUsize::new(0);
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Create a token that is cancelled whenever this token is.
+    pub fn child_token(&self) -> Self {
+        Self(Arc::new(Inner {
+            parent: Some(self.clone()),
+            ..Default::default()
+        }))
+    }
+
+    /// Cancel the work guarded by this token and its children.
+    pub fn cancel(&self) {
+        self.0.cancelled.store(true, Ordering::Release);
+
+        let wakers = core::mem::take(&mut *self.0.wakers.lock());
+        wakers.into_values().for_each(Waker::wake);
+    }
+
+    /// Whether this token, or any of its ancestors, has been cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.0.cancelled.load(Ordering::Acquire)
+            || self
+                .0
+                .parent
+                .as_ref()
+                .map_or(false, CancellationToken::is_cancelled)
+    }
+
+    /// A future that completes once this token is cancelled.
+    pub fn cancelled(&self) -> Cancelled<'_> {
+        Cancelled {
+            token: self,
+            key: None,
+        }
+    }
+
+    /// Register the `waker` of the future `key` with this token and its ancestors, replacing the
+    /// one registered before.
+    fn register(&self, key: usize, waker: &Waker) {
+        {
+            let mut wakers = self.0.wakers.lock();
+
+            match wakers.get(&key) {
+                Some(registered) if registered.will_wake(waker) => {}
+                _ => {
+                    wakers.insert(key, waker.clone());
+                }
+            }
+        }
+
+        if let Some(parent) = &self.0.parent {
+            parent.register(key, waker);
+        }
+    }
+
+    /// Forget the waker of the future `key`, which is not pending anymore.
+    fn unregister(&self, key: usize) {
+        self.0.wakers.lock().remove(&key);
+
+        if let Some(parent) = &self.0.parent {
+            parent.unregister(key);
+        }
+    }
+}
+
+/// Future returned by [`CancellationToken::cancelled`].
+///
+/// Its waker is registered with the token and its ancestors while it is pending, and removed
+/// once it is dropped.
+#[derive(Debug)]
+pub struct Cancelled<'a> {
+    token: &'a CancellationToken,
+    key: Option<usize>,
+}
+
+impl Future for Cancelled<'_> {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.token.is_cancelled() {
+            return Poll::Ready(());
+        }
+
+        let key = *self
+            .key
+            .get_or_insert_with(|| NEXT_KEY.fetch_add(1, Ordering::Relaxed));
+        self.token.register(key, cx.waker());
+
+        // Cancellation may have happened before the waker got registered.
+        if self.token.is_cancelled() {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl Drop for Cancelled<'_> {
+    fn drop(&mut self) {
+        if let Some(key) = self.key {
+            self.token.unregister(key);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn poll(future: &mut Cancelled<'_>) -> Poll<()> {
+        let mut cx = Context::from_waker(futures_util::task::noop_waker_ref());
+
+        Pin::new(future).poll(&mut cx)
+    }
+
+    #[test]
+    fn dropped_futures_unregister_their_wakers() {
+        let token = CancellationToken::new();
+
+        for _ in 0..100 {
+            let child = token.child_token();
+            let mut cancelled = child.cancelled();
+            assert_eq!(poll(&mut cancelled), Poll::Pending);
+            assert_eq!(poll(&mut cancelled), Poll::Pending);
+            assert_eq!(token.0.wakers.lock().len(), 1);
+        }
+
+        assert!(token.0.wakers.lock().is_empty());
+    }
+
+    #[test]
+    fn pending_futures_complete_once_an_ancestor_is_cancelled() {
+        let token = CancellationToken::new();
+        let child = token.child_token();
+        let mut cancelled = child.cancelled();
+        assert_eq!(poll(&mut cancelled), Poll::Pending);
+
+        token.cancel();
+
+        assert_eq!(poll(&mut cancelled), Poll::Ready(()));
+        assert!(token.0.wakers.lock().is_empty());
+    }
+}
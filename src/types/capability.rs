@@ -0,0 +1,157 @@
+//! Optional APIs and features offered by nodes.
+
+use super::{v1, ApiVersion, InfoResponse};
+use alloc::collections::BTreeSet;
+use alloc::{string::String, vec::Vec};
+use serde::{Deserialize, Serialize};
+
+/// An optional API or feature a node may offer, as advertised in `/api/routes` and in the
+/// `plugins` and `features` of [`InfoResponse`].
+///
+/// Calls to endpoints requiring a capability are only sent to nodes offering it, and fail with
+/// [`Error::UnsupportedByNode`](super::Error::UnsupportedByNode) if no node does:
+///
+/// ```
+/// use atoi::comm::http::DummyHttpClient;
+/// use atoi::types::{Capability, Error, Node};
+/// use atoi::Client;
+/// use std::sync::Arc;
+///
+/// let client = Client::builder()
+///     .nodes(vec!["https://example.iota.org".parse::<Node>().unwrap()])
+///     .http(Arc::new(DummyHttpClient::new()))
+///     .build();
+///
+/// let capabilities = client.capabilities().remove(0).unwrap();
+/// assert!(capabilities.supports(Capability::Indexer));
+/// assert!(capabilities.supports(Capability::PoW));
+///
+/// assert_eq!(
+///     client.request_funds("atoi1qqq").unwrap_err(),
+///     Error::UnsupportedByNode(Capability::Faucet)
+/// );
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Capability {
+    /// The core APIs of a generation (`core/v1` or `core/v2`).
+    Core(ApiVersion),
+    /// The UTXO indexer plugin (`indexer/v1`).
+    Indexer,
+    /// The faucet plugin (`faucet/v1`).
+    Faucet,
+    /// The MQTT event API (`mqtt/v1`).
+    Mqtt,
+    /// The participation (voting) plugin (`participation/v1`).
+    Participation,
+    /// Proof of work done by the node for blocks submitted without a nonce.
+    PoW,
+}
+
+impl Capability {
+    /// The capability given by a route or plugin like `indexer/v1`, if known.
+    pub fn from_route(route: &str) -> Option<Self> {
+        let (name, version) = route.split_once('/').unwrap_or((route, ""));
+
+        match name {
+            "core" if version == "v1" => Some(Self::Core(ApiVersion::V1)),
+            "core" if version == "v2" => Some(Self::Core(ApiVersion::V2)),
+            "indexer" => Some(Self::Indexer),
+            "faucet" => Some(Self::Faucet),
+            "mqtt" => Some(Self::Mqtt),
+            "participation" => Some(Self::Participation),
+            _ => None,
+        }
+    }
+
+    /// The capability given by a feature like `PoW`, if known.
+    pub fn from_feature(feature: &str) -> Option<Self> {
+        if feature.eq_ignore_ascii_case("pow") {
+            Some(Self::PoW)
+        } else {
+            None
+        }
+    }
+}
+
+/// The capabilities discovered on a node.
+#[derive(Clone, PartialEq, Eq, Debug, Default, Serialize, Deserialize)]
+pub struct Capabilities {
+    capabilities: BTreeSet<Capability>,
+    /// All routes, plugins and features advertised by the node, including unknown ones.
+    pub routes: Vec<String>,
+}
+
+impl Capabilities {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Whether the node offers `capability`.
+    pub fn supports(&self, capability: Capability) -> bool {
+        self.capabilities.contains(&capability)
+    }
+
+    /// The newest API generation spoken by the node, if known.
+    pub fn api_version(&self) -> Option<ApiVersion> {
+        self.capabilities
+            .iter()
+            .rev()
+            .find_map(|capability| match capability {
+                Capability::Core(version) => Some(*version),
+                _ => None,
+            })
+    }
+
+    /// The known capabilities of the node.
+    pub fn iter(&self) -> impl Iterator<Item = Capability> + '_ {
+        self.capabilities.iter().copied()
+    }
+
+    /// Record a route or plugin like `indexer/v1`.
+    pub fn add_route(&mut self, route: &str) {
+        self.capabilities.extend(Capability::from_route(route));
+        self.remember(route);
+    }
+
+    /// Record a feature like `PoW`.
+    pub fn add_feature(&mut self, feature: &str) {
+        self.capabilities.extend(Capability::from_feature(feature));
+        self.remember(feature);
+    }
+
+    fn remember(&mut self, route: &str) {
+        if !self.routes.iter().any(|known| known == route) {
+            self.routes.push(route.into());
+        }
+    }
+}
+
+impl From<&InfoResponse> for Capabilities {
+    fn from(info: &InfoResponse) -> Self {
+        let mut capabilities = Self::new();
+
+        capabilities.add_route(ApiVersion::V2.route());
+        info.plugins
+            .iter()
+            .for_each(|plugin| capabilities.add_route(plugin));
+        info.features
+            .iter()
+            .for_each(|feature| capabilities.add_feature(feature));
+
+        capabilities
+    }
+}
+
+impl From<&v1::InfoResponse> for Capabilities {
+    fn from(info: &v1::InfoResponse) -> Self {
+        let mut capabilities = Self::new();
+
+        capabilities.add_route(ApiVersion::V1.route());
+        info.features
+            .iter()
+            .for_each(|feature| capabilities.add_feature(feature));
+
+        capabilities
+    }
+}
@@ -0,0 +1,302 @@
+//! Client configuration.
+//!
+//! A [`Config`] describes everything needed to construct a client without writing code: the nodes
+//! to connect to, their authentication, the communication backend and how failed requests are
+//! handled. It can be deserialized from JSON (or TOML with the `toml` feature), and overridden by
+//! environment variables with the `std` feature.
+
+use super::error::Error;
+use super::node::{Auth, Node};
+use super::Result;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::time::Duration;
+use serde::{Deserialize, Serialize};
+
+/// Communication backends selectable from a [`Config`].
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Backend {
+    /// [`DummyHttpClient`](crate::comm::http::DummyHttpClient).
+    Dummy,
+    /// [`CurlHttpClient`](crate::comm::http::CurlHttpClient), available with the `curl` feature.
+    Curl,
+}
+
+impl core::str::FromStr for Backend {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "dummy" => Ok(Self::Dummy),
+            "curl" => Ok(Self::Curl),
+            _ => Err(Error::ConfigError(alloc::format!(
+                "unknown backend `{}`",
+                s
+            ))),
+        }
+    }
+}
+
+/// Quorum settings: how many nodes are asked, and how many of them have to agree.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+pub struct Quorum {
+    /// The number of nodes a request is sent to.
+    pub size: usize,
+    /// The minimum number of identical responses for a result to be accepted.
+    pub threshold: usize,
+}
+
+/// Limits of the rate of requests sent to a single node.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+pub struct RateLimit {
+    /// The sustained number of requests per second.
+    pub requests_per_second: u32,
+    /// The number of requests that may be sent at once after a quiet period.
+    pub burst: u32,
+}
+
+/// Configuration of a client.
+///
+/// ```
+/// use atoi::types::{Backend, Config};
+///
+/// let config = Config::from_json(
+///     r#"{
+///         "nodes": ["https://example.iota.org", { "url": "http://localhost:14265" }],
+///         "auth": { "jwt": "token" },
+///         "backend": "dummy",
+///         "retries": 2
+///     }"#,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(config.backend, Some(Backend::Dummy));
+/// assert_eq!(config.retries, 2);
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Nodes to connect to, in the order of preference.
+    pub nodes: Vec<Node>,
+    /// Authentication applied to every node that has no authentication of its own.
+    pub auth: Option<Auth>,
+    /// The communication backend. Without one, a client has to be given an HTTP client manually.
+    pub backend: Option<Backend>,
+    /// Timeout of a single request in milliseconds, if supported by the backend.
+    pub timeout_ms: Option<u64>,
+    /// How many times a failed request is retried. Each retry is sent to the next node.
+    pub retries: usize,
+    /// Quorum settings for requests querying the ledger.
+    pub quorum: Option<Quorum>,
+    /// Number of responses kept in an in-memory cache, e.g.
+    /// [`DEFAULT_CAPACITY`](crate::cache::DEFAULT_CAPACITY). Without it, or with zero, responses
+    /// are not cached, like with a client built without a cache.
+    pub cache_capacity: Option<usize>,
+    /// Maximum number of requests in flight during batch calls.
+    pub concurrency: Option<usize>,
+    /// Limits of the rate of requests sent to each node.
+    pub rate_limit: Option<RateLimit>,
+}
+
+impl Config {
+    /// Parse a configuration from JSON.
+    pub fn from_json(json: &str) -> Result<Self> {
+        serde_json::from_str(json).map_err(|err| Error::ConfigError(err.to_string()))
+    }
+
+    /// Parse a configuration from TOML.
+    #[cfg(feature = "toml")]
+    pub fn from_toml(toml: &str) -> Result<Self> {
+        toml::from_str(toml).map_err(|err| Error::ConfigError(err.to_string()))
+    }
+
+    /// Read a configuration file, whose format is determined by its extension (`.json` or
+    /// `.toml`).
+    #[cfg(feature = "std")]
+    pub fn from_file<P: AsRef<std::path::Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let content =
+            std::fs::read_to_string(path).map_err(|err| Error::ConfigError(err.to_string()))?;
+
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("json") => Self::from_json(&content),
+            #[cfg(feature = "toml")]
+            Some("toml") => Self::from_toml(&content),
+            _ => Err(Error::ConfigError(alloc::format!(
+                "unsupported configuration file `{}`",
+                path.display()
+            ))),
+        }
+    }
+
+    /// Override settings with environment variables, if they are set.
+    ///
+    /// - `ATOI_NODES`: a comma-separated list of node URLs (possibly with credentials), replacing
+    ///   [`nodes`](Self::nodes).
+    /// - `ATOI_JWT`: a JSON Web Token, replacing [`auth`](Self::auth).
+    /// - `ATOI_BACKEND`: `curl` or `dummy`, replacing [`backend`](Self::backend).
+    /// - `ATOI_TIMEOUT_MS`: replacing [`timeout_ms`](Self::timeout_ms).
+    /// - `ATOI_RETRIES`: replacing [`retries`](Self::retries).
+    ///
+    /// Clients constructed with `from_config` apply them already.
+    #[cfg(feature = "std")]
+    pub fn apply_env(&mut self) -> Result<()> {
+        self.apply_vars(|key| std::env::var(key).ok())
+    }
+
+    /// Override settings with the variables given by `var`, as in [`apply_env`](Self::apply_env).
+    #[cfg(feature = "std")]
+    fn apply_vars<F: Fn(&str) -> Option<std::string::String>>(&mut self, var: F) -> Result<()> {
+        let var = |key| var(key).filter(|value| !value.is_empty());
+
+        fn parse<T: core::str::FromStr>(key: &str, value: &str) -> Result<T> {
+            value.parse().map_err(|_| {
+                Error::ConfigError(alloc::format!("invalid value `{}` of `{}`", value, key))
+            })
+        }
+
+        if let Some(nodes) = var("ATOI_NODES") {
+            self.nodes = nodes
+                .split(',')
+                .map(str::trim)
+                .filter(|url| !url.is_empty())
+                .map(str::parse)
+                .collect::<Result<_>>()?;
+        }
+
+        if let Some(jwt) = var("ATOI_JWT") {
+            self.auth = Some(Auth::Jwt(jwt));
+        }
+
+        if let Some(backend) = var("ATOI_BACKEND") {
+            self.backend = Some(backend.parse()?);
+        }
+
+        if let Some(timeout_ms) = var("ATOI_TIMEOUT_MS") {
+            self.timeout_ms = Some(parse("ATOI_TIMEOUT_MS", &timeout_ms)?);
+        }
+
+        if let Some(retries) = var("ATOI_RETRIES") {
+            self.retries = parse("ATOI_RETRIES", &retries)?;
+        }
+
+        Ok(())
+    }
+
+    /// The configured nodes, with [`auth`](Self::auth) filled into those without authentication.
+    pub fn resolved_nodes(&self) -> Vec<Node> {
+        self.nodes
+            .iter()
+            .cloned()
+            .map(|mut node| {
+                if node.auth.is_none() {
+                    node.auth = self.auth.clone();
+                }
+                node
+            })
+            .collect()
+    }
+
+    /// The configured timeout as a [`Duration`].
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout_ms.map(Duration::from_millis)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    fn apply(config: &mut Config, vars: &[(&str, &str)]) -> Result<()> {
+        let vars: BTreeMap<&str, &str> = vars.iter().copied().collect();
+
+        config.apply_vars(|key| vars.get(key).map(|value| value.to_string()))
+    }
+
+    fn from_file() -> Config {
+        Config::from_json(
+            r#"{
+                "nodes": ["https://file.example.iota.org"],
+                "auth": { "jwt": "from-file" },
+                "timeout_ms": 1000,
+                "retries": 1,
+                "cache_capacity": 64
+            }"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn environment_overrides_the_file() {
+        let mut config = from_file();
+
+        apply(
+            &mut config,
+            &[
+                (
+                    "ATOI_NODES",
+                    "https://a.example.iota.org, https://b.example.iota.org",
+                ),
+                ("ATOI_JWT", "from-env"),
+                ("ATOI_BACKEND", "dummy"),
+                ("ATOI_TIMEOUT_MS", "2500"),
+                ("ATOI_RETRIES", "4"),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.nodes,
+            vec![
+                "https://a.example.iota.org".parse::<Node>().unwrap(),
+                "https://b.example.iota.org".parse::<Node>().unwrap(),
+            ]
+        );
+        assert_eq!(config.auth, Some(Auth::Jwt("from-env".into())));
+        assert_eq!(config.backend, Some(Backend::Dummy));
+        assert_eq!(config.timeout(), Some(Duration::from_millis(2500)));
+        assert_eq!(config.retries, 4);
+        assert_eq!(config.cache_capacity, Some(64));
+    }
+
+    #[test]
+    fn unset_and_empty_variables_keep_the_file() {
+        let mut config = from_file();
+
+        apply(&mut config, &[("ATOI_NODES", ""), ("ATOI_JWT", "")]).unwrap();
+
+        assert_eq!(config, from_file());
+    }
+
+    #[test]
+    fn rejects_invalid_values() {
+        for (key, value) in [
+            ("ATOI_RETRIES", "many"),
+            ("ATOI_TIMEOUT_MS", "-1"),
+            ("ATOI_BACKEND", "hyper"),
+            ("ATOI_NODES", "not a url"),
+        ] {
+            let mut config = from_file();
+
+            assert!(
+                matches!(
+                    apply(&mut config, &[(key, value)]),
+                    Err(Error::ConfigError(_) | Error::InvalidUrl(_))
+                ),
+                "{}={}",
+                key,
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_unknown_fields() {
+        assert!(matches!(
+            Config::from_json(r#"{ "node": "https://example.iota.org" }"#),
+            Err(Error::ConfigError(_))
+        ));
+    }
+}
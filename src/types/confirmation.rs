@@ -0,0 +1,122 @@
+//! Waiting for blocks and transactions to be confirmed by milestones.
+
+use super::{BlockId, BlockMetadata, ConflictReason, LedgerInclusionState};
+use core::time::Duration;
+use typed_builder::TypedBuilder;
+
+/// How to wait for a confirmation.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, TypedBuilder)]
+pub struct WaitOptions {
+    /// How long to wait between polls of the metadata.
+    #[builder(default = WaitOptions::DEFAULT_INTERVAL)]
+    pub interval: Duration,
+    /// How long to wait at most before failing with [`Error::Timeout`](super::Error::Timeout).
+    #[builder(default = WaitOptions::DEFAULT_TIMEOUT)]
+    pub timeout: Duration,
+}
+
+impl WaitOptions {
+    /// How long to wait between polls by default.
+    pub const DEFAULT_INTERVAL: Duration = Duration::from_secs(1);
+    /// How long to wait at most by default.
+    pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
+}
+
+impl Default for WaitOptions {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+/// The confirmation of a block by a milestone.
+///
+/// ```
+/// use atoi::comm::http::DummyHttpClient;
+/// use atoi::types::{LedgerInclusionState, Node, TransactionId, WaitOptions};
+/// use atoi::Client;
+/// use std::sync::Arc;
+///
+/// let client = Client::builder()
+///     .nodes(vec!["https://example.iota.org".parse::<Node>().unwrap()])
+///     .http(Arc::new(DummyHttpClient::new()))
+///     .build();
+/// let transaction_id: TransactionId =
+///     "0x5f6e7d8c9b0a1f2e3d4c5b6a7980f1e2d3c4b5a697887766554433221100ffee"
+///         .parse()
+///         .unwrap();
+///
+/// let confirmation = client
+///     .wait_for_transaction_confirmation(transaction_id, &WaitOptions::default())
+///     .unwrap();
+/// assert_eq!(confirmation.milestone_index, 470);
+/// assert!(confirmation.is_included());
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct Confirmation {
+    pub block_id: BlockId,
+    /// The index of the milestone referencing the block.
+    pub milestone_index: u32,
+    /// Whether the payload of the block changed the ledger, if the node told.
+    pub ledger_inclusion_state: Option<LedgerInclusionState>,
+    /// Why the transaction of the block was not applied, if it is conflicting.
+    pub conflict_reason: Option<ConflictReason>,
+}
+
+impl Confirmation {
+    /// The confirmation described by `metadata`, once the block is referenced, with or without
+    /// its ledger inclusion state.
+    pub fn from_metadata(metadata: &BlockMetadata) -> Option<Self> {
+        Some(Self {
+            block_id: metadata.block_id,
+            milestone_index: metadata.referenced_by_milestone_index?,
+            ledger_inclusion_state: metadata.ledger_inclusion_state,
+            conflict_reason: metadata.conflict_reason,
+        })
+    }
+
+    /// Whether the transaction of the block was applied to the ledger.
+    pub fn is_included(&self) -> bool {
+        self.ledger_inclusion_state == Some(LedgerInclusionState::Included)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    fn metadata(
+        referenced_by_milestone_index: Option<u32>,
+        ledger_inclusion_state: Option<LedgerInclusionState>,
+    ) -> BlockMetadata {
+        BlockMetadata {
+            block_id: "0x9a1a0b1f81a4c25d9d6d0e8c4e6a2d8d4e0f5c2b1a3e7d6c5b4a39281706f5e4"
+                .parse()
+                .unwrap(),
+            parents: Vec::new(),
+            is_solid: true,
+            referenced_by_milestone_index,
+            milestone_index: None,
+            ledger_inclusion_state,
+            conflict_reason: None,
+            white_flag_index: None,
+            should_promote: None,
+            should_reattach: None,
+        }
+    }
+
+    #[test]
+    fn referenced_blocks_are_confirmed() {
+        assert_eq!(Confirmation::from_metadata(&metadata(None, None)), None);
+
+        let confirmation = Confirmation::from_metadata(&metadata(Some(470), None)).unwrap();
+        assert_eq!(confirmation.milestone_index, 470);
+        assert_eq!(confirmation.ledger_inclusion_state, None);
+        assert!(!confirmation.is_included());
+
+        let confirmation =
+            Confirmation::from_metadata(&metadata(Some(470), Some(LedgerInclusionState::Included)))
+                .unwrap();
+        assert!(confirmation.is_included());
+    }
+}
@@ -0,0 +1,30 @@
+//! Types of the node control routes.
+
+use alloc::string::String;
+use serde::{Deserialize, Serialize};
+
+/// Which part of the database to prune.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PruneTarget {
+    /// Prune everything up to this milestone index.
+    Index(u32),
+    /// Prune everything but this many of the latest milestones.
+    Depth(u32),
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PruneDatabaseResponse {
+    /// The index of the oldest milestone left after pruning.
+    pub index: u32,
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateSnapshotResponse {
+    /// The milestone index the snapshot was taken at.
+    pub index: u32,
+    /// Where the node wrote the snapshot, on its own file system.
+    pub file_path: String,
+}
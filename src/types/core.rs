@@ -71,3 +71,9 @@ pub struct InfoResponse {
     pub features: Vec<String>,
     pub plugins: Vec<String>,
 }
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RoutesResponse {
+    pub routes: Vec<String>,
+}
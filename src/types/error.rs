@@ -1,6 +1,7 @@
 //! The error type used across the library.
 
 use alloc::string::{String, ToString};
+use serde::Deserialize;
 
 /// The error type used across the library.
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
@@ -11,18 +12,163 @@ pub enum Error {
     /// No HTTP client has been configured during client construction.
     MissingHttpClient,
 
+    /// A deadline has been set, but no timer has been configured to enforce it.
+    MissingTimer,
+
+    /// An invalid node URL was given.
+    InvalidUrl(String),
+
+    /// An invalid identifier (of a block, an output, ...) was given.
+    InvalidId(String),
+
+    /// A block that cannot be valid was about to be built or sent.
+    InvalidBlock(String),
+
+    /// A request could not be built, e.g. its body could not be serialized.
+    RequestError(String),
+
+    /// An invalid configuration was given.
+    ConfigError(String),
+
     /// An error was returned by the node software.
     NodeError {
         url: String,
+        /// The HTTP status code.
         code: u16,
+        kind: NodeErrorKind,
         reason: String,
     },
 
+    /// No node offers the API or feature required by a call.
+    UnsupportedByNode(super::Capability),
+
+    /// Not enough nodes returned agreeing responses to reach the configured quorum.
+    QuorumNotReached,
+
+    /// A node responded `429 Too Many Requests`, optionally asking to wait for some seconds.
+    RateLimited {
+        url: String,
+        retry_after: Option<u64>,
+    },
+
     /// An invalid / malformed response was received from a node.
     ResponseError(String),
 
     /// An error was returned by the HTTP client.
     HttpClientError(String),
+
+    /// The deadline of a call passed before it finished.
+    Timeout,
+
+    /// A call was cancelled through its [`CancellationToken`](super::CancellationToken).
+    Cancelled,
+}
+
+impl Error {
+    /// The error for a response of status `code` from `url`, with the reason taken from the
+    /// [error body][tip-25] `{"error": {"code": ..., "message": ...}}` if there is one, or else
+    /// from the whole body.
+    ///
+    /// ```
+    /// use atoi::types::{Error, NodeErrorKind};
+    ///
+    /// let body = br#"{"error": {"code": "400", "message": "invalid block: no parents"}}"#;
+    ///
+    /// assert_eq!(
+    ///     Error::from_response("https://example.iota.org/api/v2/blocks", 400, body),
+    ///     Error::NodeError {
+    ///         url: "https://example.iota.org/api/v2/blocks".into(),
+    ///         code: 400,
+    ///         kind: NodeErrorKind::BadRequest,
+    ///         reason: "invalid block: no parents".into(),
+    ///     }
+    /// );
+    ///
+    /// let error = Error::from_response("https://example.iota.org/api/v2/blocks/0x00", 404, b"");
+    /// assert_eq!(error.node_error_kind(), Some(NodeErrorKind::NotFound));
+    /// ```
+    ///
+    /// [tip-25]: https://github.com/iotaledger/tips/pull/57
+    pub fn from_response(url: &str, code: u16, body: &[u8]) -> Self {
+        #[derive(Deserialize)]
+        struct ErrorBody {
+            error: ErrorObject,
+        }
+
+        #[derive(Deserialize)]
+        struct ErrorObject {
+            message: String,
+        }
+
+        let reason = match serde_json::from_slice::<ErrorBody>(body) {
+            Ok(body) => body.error.message,
+            Err(_) => String::from_utf8_lossy(body).trim().into(),
+        };
+
+        Self::NodeError {
+            url: url.into(),
+            code,
+            kind: NodeErrorKind::from_code(code),
+            reason,
+        }
+    }
+
+    /// Whether the error may not happen again: the node could not be reached, is rate limiting,
+    /// or failed with a `5xx` status code.
+    pub fn is_transient(&self) -> bool {
+        matches!(
+            self,
+            Self::HttpClientError(_)
+                | Self::RateLimited { .. }
+                | Self::NodeError {
+                    code: 500..=599,
+                    ..
+                }
+        )
+    }
+
+    /// Whether polling a resource again may succeed after this error: it is
+    /// [transient](Self::is_transient), or the node does not know the resource yet.
+    #[cfg(any(all(feature = "sync", feature = "std"), feature = "async"))]
+    pub(crate) fn may_clear(&self) -> bool {
+        self.is_transient() || self.node_error_kind() == Some(NodeErrorKind::NotFound)
+    }
+
+    /// The kind of the error, if returned by the node software.
+    pub fn node_error_kind(&self) -> Option<NodeErrorKind> {
+        match self {
+            Self::NodeError { kind, .. } => Some(*kind),
+            _ => None,
+        }
+    }
+}
+
+/// The kind of an [`Error::NodeError`], by HTTP status code, to branch on.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum NodeErrorKind {
+    /// `400 Bad Request`: the request was invalid, e.g. a block was rejected.
+    BadRequest,
+    /// `401 Unauthorized` or `403 Forbidden`: the credentials are missing or insufficient.
+    Forbidden,
+    /// `404 Not Found`: the node does not know the resource (yet), or does not serve the route.
+    NotFound,
+    /// `503 Service Unavailable`: the node is not synced, or the route is disabled.
+    ServiceUnavailable,
+    /// Any other status code.
+    Other,
+}
+
+impl NodeErrorKind {
+    /// The kind of errors of status `code`.
+    pub fn from_code(code: u16) -> Self {
+        match code {
+            400 => Self::BadRequest,
+            401 | 403 => Self::Forbidden,
+            404 => Self::NotFound,
+            503 => Self::ServiceUnavailable,
+            _ => Self::Other,
+        }
+    }
 }
 
 impl From<serde_json::Error> for Error {
@@ -37,3 +183,89 @@ impl From<curl::Error> for Error {
         Self::HttpClientError(curl_error.to_string())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const URL: &str = "https://example.iota.org/api/v2/blocks";
+
+    #[test]
+    fn parses_tip_25_error_bodies() {
+        let cases: [(u16, &[u8], NodeErrorKind, &str); 5] = [
+            (
+                400,
+                br#"{"error":{"code":"400","message":"invalid block, error: no parents"}}"#,
+                NodeErrorKind::BadRequest,
+                "invalid block, error: no parents",
+            ),
+            (
+                401,
+                br#"{"error":{"code":"401","message":"missing or malformed jwt"}}"#,
+                NodeErrorKind::Forbidden,
+                "missing or malformed jwt",
+            ),
+            (
+                403,
+                br#"{"error":{"code":"403","message":"route is not allowed"}}"#,
+                NodeErrorKind::Forbidden,
+                "route is not allowed",
+            ),
+            (
+                404,
+                br#"{"error":{"code":"404","message":"block not found: 0x00"}}"#,
+                NodeErrorKind::NotFound,
+                "block not found: 0x00",
+            ),
+            (
+                503,
+                br#"{"error":{"code":"503","message":"node is not synced"}}"#,
+                NodeErrorKind::ServiceUnavailable,
+                "node is not synced",
+            ),
+        ];
+
+        for (code, body, kind, reason) in cases {
+            assert_eq!(
+                Error::from_response(URL, code, body),
+                Error::NodeError {
+                    url: URL.into(),
+                    code,
+                    kind,
+                    reason: reason.into(),
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn keeps_other_bodies_as_reason() {
+        let error = Error::from_response(URL, 502, b"  <html>Bad Gateway</html>\n");
+        assert_eq!(
+            error,
+            Error::NodeError {
+                url: URL.into(),
+                code: 502,
+                kind: NodeErrorKind::Other,
+                reason: "<html>Bad Gateway</html>".into(),
+            }
+        );
+
+        let error = Error::from_response(URL, 404, br#"{"message":"not the TIP-25 shape"}"#);
+        assert_eq!(error.node_error_kind(), Some(NodeErrorKind::NotFound));
+        assert!(matches!(
+            error,
+            Error::NodeError { reason, .. } if reason == r#"{"message":"not the TIP-25 shape"}"#
+        ));
+    }
+
+    #[test]
+    fn only_node_errors_have_a_kind() {
+        assert_eq!(NodeErrorKind::from_code(500), NodeErrorKind::Other);
+        assert_eq!(Error::Timeout.node_error_kind(), None);
+        assert_eq!(
+            Error::ResponseError("bad JSON".into()).node_error_kind(),
+            None
+        );
+    }
+}
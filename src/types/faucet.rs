@@ -0,0 +1,11 @@
+//! Types returned by the Faucet plugin API.
+
+use alloc::string::String;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FaucetEnqueueResponse {
+    pub address: String,
+    pub waiting_requests: u64,
+}
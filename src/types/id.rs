@@ -0,0 +1,164 @@
+//! Identifiers of objects on the Tangle, written as `0x`-prefixed hex strings (plain hex strings in
+//! the Chrysalis API).
+
+use super::error::Error;
+use super::Result;
+use alloc::string::String;
+use core::fmt;
+use core::str::FromStr;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Define an identifier of `$len` bytes, written as hex digits following `$prefix` (`0x` unless
+/// given).
+macro_rules! define_id {
+    ($(#[$meta:meta])* $name:ident, $len:expr) => {
+        define_id!($(#[$meta])* $name, $len, "0x");
+    };
+    ($(#[$meta:meta])* $name:ident, $len:expr, $prefix:expr) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $name(pub [u8; $len]);
+
+        impl $name {
+            /// The length of the identifier in bytes.
+            pub const LENGTH: usize = $len;
+        }
+
+        impl FromStr for $name {
+            type Err = Error;
+
+            fn from_str(s: &str) -> Result<Self> {
+                let mut bytes = [0; $len];
+                decode_into(s, $prefix, &mut bytes)?;
+                Ok(Self(bytes))
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}{}", $prefix, hex::encode(self.0))
+            }
+        }
+
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}({})", stringify!($name), self)
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(
+                &self,
+                serializer: S,
+            ) -> core::result::Result<S::Ok, S::Error> {
+                serializer.collect_str(self)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(
+                deserializer: D,
+            ) -> core::result::Result<Self, D::Error> {
+                let id = String::deserialize(deserializer)?;
+                id.parse()
+                    .map_err(|err| <D::Error as serde::de::Error>::custom(alloc::format!("{:?}", err)))
+            }
+        }
+    };
+}
+
+define_id!(
+    /// The identifier of a block: the BLAKE2b-256 hash of its serialized form.
+    ///
+    /// ```
+    /// use atoi::types::BlockId;
+    ///
+    /// let id = "0x9a1a0b1f81a4c25d9d6d0e8c4e6a2d8d4e0f5c2b1a3e7d6c5b4a39281706f5e4";
+    /// assert_eq!(id.parse::<BlockId>().unwrap().to_string(), id);
+    /// assert!("0x9a1a".parse::<BlockId>().is_err());
+    /// ```
+    BlockId,
+    32
+);
+
+define_id!(
+    /// The identifier of a transaction: the BLAKE2b-256 hash of its essence.
+    TransactionId,
+    32
+);
+
+define_id!(
+    /// The identifier of an output: the ID of the transaction creating it, followed by its index in
+    /// the outputs of that transaction (as a little-endian `u16`).
+    ///
+    /// ```
+    /// use atoi::types::OutputId;
+    ///
+    /// let id: OutputId = "0x9a1a0b1f81a4c25d9d6d0e8c4e6a2d8d4e0f5c2b1a3e7d6c5b4a39281706f5e40100"
+    ///     .parse()
+    ///     .unwrap();
+    /// assert_eq!(id.index(), 1);
+    /// assert_eq!(OutputId::new(id.transaction_id(), 1), id);
+    /// ```
+    OutputId,
+    34
+);
+
+define_id!(
+    /// The identifier of a milestone: the BLAKE2b-256 hash of its essence.
+    MilestoneId,
+    32
+);
+
+define_id!(
+    /// The identifier of a Chrysalis message, written without a `0x` prefix.
+    ///
+    /// ```
+    /// use atoi::types::v1::MessageId;
+    ///
+    /// let id = "9b6d8b6f7e2dcf9c1b4a4b08e3e8f1d1f0f6a0d3b0d5e5f7e5b0c8a6f1b2c3d4";
+    /// assert_eq!(id.parse::<MessageId>().unwrap().to_string(), id);
+    /// assert!("0x9b6d8b6f7e2dcf9c1b4a4b08e3e8f1d1f0f6a0d3b0d5e5f7e5b0c8a6f1b2c3d4"
+    ///     .parse::<MessageId>()
+    ///     .is_err());
+    /// assert!("../info".parse::<MessageId>().is_err());
+    /// ```
+    MessageId,
+    32,
+    ""
+);
+
+impl OutputId {
+    /// The ID of output `index` of transaction `transaction_id`.
+    pub fn new(transaction_id: TransactionId, index: u16) -> Self {
+        let mut bytes = [0; Self::LENGTH];
+        bytes[..TransactionId::LENGTH].copy_from_slice(&transaction_id.0);
+        bytes[TransactionId::LENGTH..].copy_from_slice(&index.to_le_bytes());
+        Self(bytes)
+    }
+
+    /// The ID of the transaction creating the output.
+    pub fn transaction_id(&self) -> TransactionId {
+        let mut bytes = [0; TransactionId::LENGTH];
+        bytes.copy_from_slice(&self.0[..TransactionId::LENGTH]);
+        TransactionId(bytes)
+    }
+
+    /// The index of the output in the outputs of its transaction.
+    pub fn index(&self) -> u16 {
+        u16::from_le_bytes([
+            self.0[TransactionId::LENGTH],
+            self.0[TransactionId::LENGTH + 1],
+        ])
+    }
+}
+
+/// Decode a `prefix`ed hex string into `bytes`, which it must fill exactly.
+fn decode_into(s: &str, prefix: &str, bytes: &mut [u8]) -> Result<()> {
+    let digits = s
+        .strip_prefix(prefix)
+        .ok_or_else(|| Error::InvalidId(alloc::format!("`{}`: missing `{}` prefix", s, prefix)))?;
+
+    hex::decode_to_slice(digits, bytes)
+        .map_err(|err| Error::InvalidId(alloc::format!("`{}`: {}", s, err)))
+}
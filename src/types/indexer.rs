@@ -0,0 +1,17 @@
+//! Types returned by the UTXO indexer API.
+
+use super::OutputId;
+use alloc::{string::String, vec::Vec};
+use serde::{Deserialize, Serialize};
+
+/// A page of output IDs found by the indexer.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexerOutputsResponse {
+    /// The index of the confirmed milestone the outputs were found at.
+    pub ledger_index: u32,
+    /// The cursor of the next page, if there are more outputs.
+    #[serde(default)]
+    pub cursor: Option<String>,
+    pub items: Vec<OutputId>,
+}
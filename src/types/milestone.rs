@@ -0,0 +1,96 @@
+//! Milestones, issued by the Coordinator to confirm parts of the Tangle.
+
+use super::{BlockId, MilestoneId, OutputId};
+use alloc::{string::String, vec::Vec};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// A milestone to look up, by its ID or by its index.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum MilestoneRef {
+    Id(MilestoneId),
+    Index(u32),
+}
+
+impl From<MilestoneId> for MilestoneRef {
+    fn from(milestone_id: MilestoneId) -> Self {
+        Self::Id(milestone_id)
+    }
+}
+
+impl From<u32> for MilestoneRef {
+    fn from(index: u32) -> Self {
+        Self::Index(index)
+    }
+}
+
+/// A milestone payload, in its JSON form.
+///
+/// ```
+/// use atoi::comm::http::DummyHttpClient;
+/// use atoi::types::{MilestoneId, Node};
+/// use atoi::Client;
+/// use std::sync::Arc;
+///
+/// let client = Client::builder()
+///     .nodes(vec!["https://example.iota.org".parse::<Node>().unwrap()])
+///     .http(Arc::new(DummyHttpClient::new()))
+///     .build();
+///
+/// let milestone = client.get_milestone(470).unwrap();
+/// let milestone_id: MilestoneId =
+///     "0x7a09324557e9200f39bf493fc8fd6ac43e9ca750c6f6d884cc72386ddcb7d695"
+///         .parse()
+///         .unwrap();
+/// assert_eq!(client.get_milestone(milestone_id).unwrap(), milestone);
+///
+/// let changes = client.get_utxo_changes(milestone.index).unwrap();
+/// assert_eq!(changes.created_outputs.len(), 2);
+/// assert_eq!(changes.consumed_outputs.len(), 1);
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MilestonePayload {
+    /// Always [`Payload::MILESTONE_TYPE`](super::Payload::MILESTONE_TYPE).
+    #[serde(rename = "type")]
+    pub kind: u64,
+    pub index: u32,
+    /// Seconds since the Unix epoch.
+    pub timestamp: u32,
+    pub protocol_version: u8,
+    pub previous_milestone_id: MilestoneId,
+    pub parents: Vec<BlockId>,
+    /// The `0x`-prefixed hex-encoded Merkle root of the blocks newly referenced by the milestone.
+    pub inclusion_merkle_root: String,
+    /// The `0x`-prefixed hex-encoded Merkle root of the blocks newly applied to the ledger.
+    pub applied_merkle_root: String,
+    /// The `0x`-prefixed hex-encoded metadata.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub metadata: String,
+    /// Milestone options (receipts, protocol parameters, ...), kept as raw JSON.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub options: Vec<Value>,
+    pub signatures: Vec<MilestoneSignature>,
+}
+
+/// An Ed25519 signature of a milestone.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MilestoneSignature {
+    #[serde(rename = "type")]
+    pub kind: u64,
+    /// The `0x`-prefixed hex-encoded public key.
+    pub public_key: String,
+    /// The `0x`-prefixed hex-encoded signature.
+    pub signature: String,
+}
+
+/// The outputs created and consumed by the transactions a milestone applied to the ledger.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UtxoChangesResponse {
+    /// The index of the milestone.
+    pub index: u32,
+    pub created_outputs: Vec<OutputId>,
+    pub consumed_outputs: Vec<OutputId>,
+}
@@ -1,15 +1,68 @@
 //! Types used across the library.
 
+mod block;
+mod cancel;
+mod capability;
+mod config;
+mod confirmation;
+mod control;
 mod core;
 mod error;
+mod faucet;
+mod id;
+mod indexer;
+mod milestone;
+mod network;
 mod node;
+mod node_url;
+mod output;
+mod participation;
+mod peer;
 mod preset_node;
+mod probe;
+mod promotion;
+mod receipt;
+mod tips;
+mod transaction;
+pub mod v1;
+mod version;
 
 // Exports.
+pub use self::block::{
+    Block, BlockMetadata, ConflictReason, LedgerInclusionState, Payload, SubmitBlockResponse,
+    TaggedDataPayload,
+};
+pub use self::cancel::{CancellationToken, Cancelled};
+pub use self::capability::{Capabilities, Capability};
+pub use self::config::{Backend, Config, Quorum, RateLimit};
+pub use self::confirmation::{Confirmation, WaitOptions};
+pub use self::control::{CreateSnapshotResponse, PruneDatabaseResponse, PruneTarget};
 pub use self::core::*;
-pub use self::error::Error;
+pub use self::error::{Error, NodeErrorKind};
+pub use self::faucet::*;
+pub use self::id::{BlockId, MilestoneId, OutputId, TransactionId};
+pub use self::indexer::IndexerOutputsResponse;
+pub use self::milestone::{
+    MilestonePayload, MilestoneRef, MilestoneSignature, UtxoChangesResponse,
+};
+pub use self::network::{Network, NetworkManifest};
 pub use self::node::{Auth, Node};
+pub use self::node_url::NodeUrl;
+pub use self::output::{Output, OutputMetadata, OutputResponse};
+pub use self::participation::ParticipationEventsResponse;
+pub use self::peer::{Gossip, GossipMetrics, Heartbeat, Peer, PeerRelation};
 pub use self::preset_node::PresetNode;
+pub use self::probe::{NodeProbe, ProbeReport};
+#[cfg(any(all(feature = "sync", feature = "std"), feature = "async"))]
+pub(crate) use self::promotion::Reaction;
+pub use self::promotion::{PromotionAction, PromotionError, PromotionPolicy, PromotionReport};
+pub use self::receipt::{
+    IncludedReceipt, MigratedFundsEntry, Receipt, ReceiptsResponse, TreasuryInput, TreasuryOutput,
+    TreasuryResponse, TreasuryTransactionPayload,
+};
+pub use self::tips::{Parents, TipsResponse};
+pub use self::transaction::TransactionStatus;
+pub use self::version::{ApiVersion, NodeInfo};
 
 /// The canonical [Result] type used across the library, with [Error] as the error type.
 ///
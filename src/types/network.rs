@@ -0,0 +1,340 @@
+//! Networks (Tangles) and the nodes serving them.
+
+use super::error::Error;
+use super::node::Node;
+use super::preset_node::PresetNode;
+use super::{NodeInfo, Result};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+
+/// A network, i.e. a Tangle, with the nodes serving it and the parameters wallets need.
+///
+/// Well-known networks are available as presets; others can be described in a
+/// [`NetworkManifest`].
+///
+/// ```
+/// use atoi::comm::http::DummyHttpClient;
+/// use atoi::types::Network;
+/// use atoi::Client;
+/// use std::sync::Arc;
+///
+/// let shimmer = Network::preset("shimmer").unwrap();
+/// assert_eq!(shimmer.bech32_hrp, "smr");
+/// assert_eq!(shimmer.coin_type, Network::SHIMMER_COIN_TYPE);
+///
+/// let client = Client::builder()
+///     .nodes(shimmer)
+///     .http(Arc::new(DummyHttpClient::new()))
+///     .build();
+/// assert_eq!(client.nodes[0].url.as_str(), "https://api.shimmer.network");
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Network {
+    /// A short name identifying the network, e.g. `shimmer`.
+    pub name: String,
+    /// Nodes serving the network, in the order of preference.
+    pub nodes: Vec<Node>,
+    /// The name nodes of the network report, e.g. `shimmer`; nodes reporting it followed by a
+    /// `-` suffix (e.g. a version, as in `testnet-1`) serve the network too.
+    #[serde(default)]
+    pub network_name: Option<String>,
+    /// The human-readable part of Bech32 addresses on the network.
+    pub bech32_hrp: String,
+    /// The BIP44 coin type of the tokens of the network.
+    pub coin_type: u32,
+    /// The base URL of a Tangle explorer for the network.
+    #[serde(default)]
+    pub explorer: Option<String>,
+}
+
+impl Network {
+    /// The BIP44 coin type of IOTA tokens.
+    pub const IOTA_COIN_TYPE: u32 = 4218;
+    /// The BIP44 coin type of Shimmer tokens.
+    pub const SHIMMER_COIN_TYPE: u32 = 4219;
+
+    /// The IOTA mainnet (Chrysalis).
+    pub fn mainnet() -> Self {
+        Self::new(
+            "mainnet",
+            Some("chrysalis-mainnet"),
+            PresetNode::Mainnet.into(),
+            "iota",
+            Self::IOTA_COIN_TYPE,
+            Some("https://explorer.iota.org/mainnet"),
+        )
+    }
+
+    /// The IOTA devnet (Chrysalis), for development and testing purposes.
+    pub fn devnet() -> Self {
+        Self::new(
+            "devnet",
+            Some("chrysalis-devnet"),
+            PresetNode::Devnet.into(),
+            "atoi",
+            Self::IOTA_COIN_TYPE,
+            Some("https://explorer.iota.org/devnet"),
+        )
+    }
+
+    /// The Shimmer network (Stardust).
+    pub fn shimmer() -> Self {
+        Self::new(
+            "shimmer",
+            Some("shimmer"),
+            urls(&["https://api.shimmer.network"]),
+            "smr",
+            Self::SHIMMER_COIN_TYPE,
+            Some("https://explorer.shimmer.network/shimmer"),
+        )
+    }
+
+    /// The public testnet of Shimmer (Stardust).
+    pub fn testnet() -> Self {
+        Self::new(
+            "testnet",
+            Some("testnet"),
+            urls(&["https://api.testnet.shimmer.network"]),
+            "rms",
+            Self::SHIMMER_COIN_TYPE,
+            Some("https://explorer.shimmer.network/testnet"),
+        )
+    }
+
+    /// The alphanet, where new Stardust features are tried out first.
+    pub fn alphanet() -> Self {
+        Self::new(
+            "alphanet",
+            Some("alphanet"),
+            urls(&["https://api.alphanet.iotaledger.net"]),
+            "rms",
+            Self::IOTA_COIN_TYPE,
+            None,
+        )
+    }
+
+    /// All preset networks.
+    pub fn presets() -> Vec<Self> {
+        alloc::vec![
+            Self::mainnet(),
+            Self::devnet(),
+            Self::shimmer(),
+            Self::testnet(),
+            Self::alphanet(),
+        ]
+    }
+
+    /// The preset network called `name`.
+    pub fn preset(name: &str) -> Option<Self> {
+        Self::presets()
+            .into_iter()
+            .find(|network| network.name == name)
+    }
+
+    /// Whether `info` comes from a node of this network, judging by its Bech32 HRP and, if the
+    /// network has one, its network name (networks may share an HRP, as the testnet and the
+    /// alphanet do).
+    ///
+    /// ```
+    /// use atoi::comm::http::DummyHttpClient;
+    /// use atoi::types::{Network, NetworkManifest, Node};
+    /// use atoi::Client;
+    /// use std::sync::Arc;
+    ///
+    /// let client = Client::builder()
+    ///     .nodes(vec!["https://example.iota.org".parse::<Node>().unwrap()])
+    ///     .http(Arc::new(DummyHttpClient::new()))
+    ///     .build();
+    /// let info = client.info().unwrap();
+    ///
+    /// // The node uses the HRP of the devnet, but is not a Chrysalis node.
+    /// assert_eq!(info.bech32_hrp(), Network::devnet().bech32_hrp);
+    /// assert!(!Network::devnet().is_served_by(&info));
+    ///
+    /// let manifest = NetworkManifest::from_json(
+    ///     r#"{
+    ///         "networks": [{
+    ///             "name": "testnet",
+    ///             "network_name": "iota-testnet",
+    ///             "nodes": ["https://example.iota.org"],
+    ///             "bech32_hrp": "atoi",
+    ///             "coin_type": 4218
+    ///         }]
+    ///     }"#,
+    /// )
+    /// .unwrap();
+    /// assert!(manifest.network("testnet").unwrap().is_served_by(&info));
+    /// ```
+    pub fn is_served_by(&self, info: &NodeInfo) -> bool {
+        info.bech32_hrp() == self.bech32_hrp
+            && self.network_name.as_ref().map_or(true, |name| {
+                let reported = info.network_name();
+                reported == name
+                    || reported
+                        .strip_prefix(name.as_str())
+                        .map_or(false, |suffix| suffix.starts_with('-'))
+            })
+    }
+
+    /// The link to `path` (e.g. `block/0x...`) on the explorer of the network, if there is one.
+    pub fn explorer_url(&self, path: &str) -> Option<String> {
+        self.explorer.as_ref().map(|explorer| {
+            alloc::format!(
+                "{}/{}",
+                explorer.trim_end_matches('/'),
+                path.trim_start_matches('/')
+            )
+        })
+    }
+
+    fn new(
+        name: &str,
+        network_name: Option<&str>,
+        nodes: Vec<Node>,
+        bech32_hrp: &str,
+        coin_type: u32,
+        explorer: Option<&str>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            network_name: network_name.map(String::from),
+            nodes,
+            bech32_hrp: bech32_hrp.into(),
+            coin_type,
+            explorer: explorer.map(String::from),
+        }
+    }
+}
+
+impl From<Network> for Vec<Node> {
+    fn from(network: Network) -> Vec<Node> {
+        network.nodes
+    }
+}
+
+fn urls(urls: &[&str]) -> Vec<Node> {
+    urls.iter()
+        .map(|url| url.parse().expect("preset node URLs are valid"))
+        .collect()
+}
+
+/// A list of networks, maintained outside of the code so node lists can be updated without
+/// recompiling.
+///
+/// ```
+/// use atoi::types::NetworkManifest;
+///
+/// let manifest = NetworkManifest::from_json(
+///     r#"{
+///         "networks": [{
+///             "name": "private",
+///             "nodes": ["http://localhost:14265"],
+///             "bech32_hrp": "tst",
+///             "coin_type": 4219
+///         }]
+///     }"#,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(manifest.network("private").unwrap().bech32_hrp, "tst");
+/// // Presets are used for networks not listed.
+/// assert_eq!(manifest.network("shimmer").unwrap().bech32_hrp, "smr");
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct NetworkManifest {
+    pub networks: Vec<Network>,
+}
+
+impl NetworkManifest {
+    /// Parse a manifest from JSON.
+    pub fn from_json(json: &str) -> Result<Self> {
+        serde_json::from_str(json).map_err(|err| Error::ConfigError(err.to_string()))
+    }
+
+    /// Parse a manifest from TOML.
+    #[cfg(feature = "toml")]
+    pub fn from_toml(toml: &str) -> Result<Self> {
+        toml::from_str(toml).map_err(|err| Error::ConfigError(err.to_string()))
+    }
+
+    /// Read a manifest file, whose format is determined by its extension (`.json` or `.toml`).
+    #[cfg(feature = "std")]
+    pub fn from_file<P: AsRef<std::path::Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let content =
+            std::fs::read_to_string(path).map_err(|err| Error::ConfigError(err.to_string()))?;
+
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("json") => Self::from_json(&content),
+            #[cfg(feature = "toml")]
+            Some("toml") => Self::from_toml(&content),
+            _ => Err(Error::ConfigError(alloc::format!(
+                "unsupported manifest file `{}`",
+                path.display()
+            ))),
+        }
+    }
+
+    /// The network called `name`: the one listed in the manifest, or else the preset one.
+    pub fn network(&self, name: &str) -> Option<Network> {
+        self.networks
+            .iter()
+            .find(|network| network.name == name)
+            .cloned()
+            .or_else(|| Network::preset(name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::v1::InfoResponse;
+
+    fn info(network_name: &str, bech32_hrp: &str) -> NodeInfo {
+        NodeInfo::Chrysalis(InfoResponse {
+            name: "HORNET".into(),
+            version: "1.2.0".into(),
+            is_healthy: true,
+            network_id: network_name.into(),
+            bech32_hrp: bech32_hrp.into(),
+            min_pow_score: 4000.0,
+            messages_per_second: 0.0,
+            referenced_messages_per_second: 0.0,
+            referenced_rate: 0.0,
+            latest_milestone_timestamp: 0,
+            latest_milestone_index: 0,
+            confirmed_milestone_index: 0,
+            pruning_index: 0,
+            features: Vec::new(),
+        })
+    }
+
+    #[test]
+    fn tells_apart_networks_sharing_an_hrp() {
+        let testnet = info("testnet-1", "rms");
+        assert!(Network::testnet().is_served_by(&testnet));
+        assert!(!Network::alphanet().is_served_by(&testnet));
+
+        let alphanet = info("alphanet", "rms");
+        assert!(Network::alphanet().is_served_by(&alphanet));
+        assert!(!Network::testnet().is_served_by(&alphanet));
+
+        assert!(!Network::testnet().is_served_by(&info("testnets", "rms")));
+        assert!(!Network::shimmer().is_served_by(&info("shimmer", "rms")));
+        assert!(Network::mainnet().is_served_by(&info("chrysalis-mainnet", "iota")));
+    }
+
+    #[test]
+    fn networks_without_a_name_are_matched_by_hrp() {
+        let network = Network {
+            network_name: None,
+            ..Network::testnet()
+        };
+
+        assert!(network.is_served_by(&info("alphanet", "rms")));
+        assert!(!network.is_served_by(&info("alphanet", "smr")));
+    }
+}
@@ -0,0 +1,94 @@
+//! Outputs of the UTXO ledger.
+
+use super::{BlockId, OutputId, TransactionId};
+use alloc::string::String;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// An output, in its JSON form.
+///
+/// Only the fields common to all outputs are typed; the others are kept as raw JSON.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct Output {
+    /// The type of the output, e.g. [`BASIC_TYPE`](Self::BASIC_TYPE).
+    #[serde(rename = "type")]
+    pub kind: u8,
+    /// The amount of base tokens held, as a decimal string.
+    pub amount: String,
+    /// The fields specific to the type of the output.
+    #[serde(flatten)]
+    pub fields: Map<String, Value>,
+}
+
+impl Output {
+    pub const TREASURY_TYPE: u8 = 2;
+    pub const BASIC_TYPE: u8 = 3;
+    pub const ALIAS_TYPE: u8 = 4;
+    pub const FOUNDRY_TYPE: u8 = 5;
+    pub const NFT_TYPE: u8 = 6;
+}
+
+/// The state of an output in the ledger.
+///
+/// ```
+/// use atoi::comm::http::DummyHttpClient;
+/// use atoi::types::{Node, OutputId, TransactionId};
+/// use atoi::Client;
+/// use std::sync::Arc;
+///
+/// let client = Client::builder()
+///     .nodes(vec!["https://example.iota.org".parse::<Node>().unwrap()])
+///     .http(Arc::new(DummyHttpClient::new()))
+///     .build();
+/// let transaction_id: TransactionId =
+///     "0x5f6e7d8c9b0a1f2e3d4c5b6a7980f1e2d3c4b5a697887766554433221100ffee"
+///         .parse()
+///         .unwrap();
+/// let output_ids = [
+///     OutputId::new(transaction_id, 0),
+///     OutputId::new(transaction_id, 1),
+/// ];
+///
+/// let spent: Vec<bool> = client
+///     .get_outputs_metadata(output_ids)
+///     .into_iter()
+///     .map(|metadata| metadata.unwrap().is_spent)
+///     .collect();
+/// assert_eq!(spent, [true, false]);
+///
+/// let output = client.get_output(output_ids[0]).unwrap();
+/// assert_eq!(output.metadata.output_id(), output_ids[0]);
+/// assert_eq!(output.output.amount, "1000000");
+/// ```
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutputMetadata {
+    /// The block containing the transaction creating the output.
+    pub block_id: BlockId,
+    pub transaction_id: TransactionId,
+    pub output_index: u16,
+    pub is_spent: bool,
+    /// The index of the milestone confirming the spending of the output, if spent.
+    pub milestone_index_spent: Option<u32>,
+    pub milestone_timestamp_spent: Option<u32>,
+    /// The transaction spending the output, if spent.
+    pub transaction_id_spent: Option<TransactionId>,
+    /// The index of the milestone confirming the creation of the output.
+    pub milestone_index_booked: u32,
+    pub milestone_timestamp_booked: u32,
+    /// The index of the milestone the ledger of the node was at when responding.
+    pub ledger_index: u32,
+}
+
+impl OutputMetadata {
+    /// The ID of the output.
+    pub fn output_id(&self) -> OutputId {
+        OutputId::new(self.transaction_id, self.output_index)
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct OutputResponse {
+    pub metadata: OutputMetadata,
+    pub output: Output,
+}
@@ -0,0 +1,11 @@
+//! Types returned by the participation plugin API.
+
+use alloc::{string::String, vec::Vec};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ParticipationEventsResponse {
+    /// The IDs of the participation events tracked by the node.
+    pub event_ids: Vec<String>,
+}
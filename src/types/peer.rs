@@ -0,0 +1,109 @@
+//! Peers of a node in the gossip network.
+
+use alloc::{string::String, vec::Vec};
+use serde::{Deserialize, Serialize};
+
+/// A peer of a node.
+///
+/// ```
+/// use atoi::comm::http::DummyHttpClient;
+/// use atoi::types::{Auth, Node, PeerRelation};
+/// use atoi::Client;
+/// use std::sync::Arc;
+///
+/// let mut node: Node = "https://example.iota.org".parse().unwrap();
+/// node.auth = Some(Auth::Jwt("eyJhbGciOiJIUzI1NiJ9".into()));
+/// let client = Client::builder()
+///     .nodes(vec![node])
+///     .http(Arc::new(DummyHttpClient::new()))
+///     .build();
+///
+/// let peer = client
+///     .add_peer(
+///         0,
+///         "/ip4/192.0.2.1/tcp/15600/p2p/12D3KooWRMquyuA1FNvkzLzGqLbhs4sRkqnZqG2DPETaBEdGhaYz",
+///         Some("hornet-1"),
+///     )
+///     .unwrap();
+/// assert_eq!(peer.relation, PeerRelation::Known);
+///
+/// let peers = client.peers(0).unwrap();
+/// assert!(peers.iter().any(|known| known.id == peer.id));
+/// assert!(peers[0].is_synced());
+///
+/// client.remove_peer(0, &peer.id).unwrap();
+/// ```
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Peer {
+    /// The libp2p identifier of the peer.
+    pub id: String,
+    /// The multiaddresses the peer is reachable at.
+    pub multi_addresses: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alias: Option<String>,
+    pub relation: PeerRelation,
+    pub connected: bool,
+    /// Gossip metrics, once connected.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gossip: Option<Gossip>,
+}
+
+impl Peer {
+    /// Whether the peer is connected and has solidified up to its latest milestone.
+    pub fn is_synced(&self) -> bool {
+        self.connected
+            && self.gossip.as_ref().map_or(false, |gossip| {
+                gossip.heartbeat.solid_milestone_index >= gossip.heartbeat.latest_milestone_index
+            })
+    }
+}
+
+/// How a peer became a peer.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PeerRelation {
+    /// The peer was added manually.
+    Known,
+    /// The peer connected on its own.
+    Unknown,
+    /// The peer was selected by autopeering.
+    Autopeered,
+}
+
+/// The gossip state of a connected peer.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Gossip {
+    /// The last heartbeat received from the peer.
+    pub heartbeat: Heartbeat,
+    pub metrics: GossipMetrics,
+}
+
+/// The state of a peer, as told by its heartbeats.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Heartbeat {
+    pub solid_milestone_index: u32,
+    pub pruned_milestone_index: u32,
+    pub latest_milestone_index: u32,
+    pub connected_peers: u32,
+    pub synced_peers: u32,
+}
+
+/// Counters of the gossip messages exchanged with a peer.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GossipMetrics {
+    pub new_blocks: u64,
+    pub known_blocks: u64,
+    pub received_blocks: u64,
+    pub received_block_requests: u64,
+    pub received_milestone_requests: u64,
+    pub received_heartbeats: u64,
+    pub sent_blocks: u64,
+    pub sent_block_requests: u64,
+    pub sent_milestone_requests: u64,
+    pub sent_heartbeats: u64,
+    pub dropped_packets: u64,
+}
@@ -1,6 +1,7 @@
 //! Preset public IOTA nodes.
 
 use super::node::Node;
+use super::node_url::NodeUrl;
 use alloc::string::String;
 use alloc::{vec, vec::Vec};
 use core::convert::From;
@@ -13,6 +14,7 @@ use core::convert::From;
 ///
 /// - [`&str`] and [`Vec<&str>`]
 /// - [`String`] and [`Vec<String>`]
+/// - [`NodeUrl`], [`Node`] and [`Vec<Node>`]
 ///
 /// ```
 /// use atoi::types::PresetNode;
@@ -121,20 +123,25 @@ impl From<PresetNode> for Vec<String> {
     }
 }
 
+impl From<PresetNode> for NodeUrl {
+    fn from(node: PresetNode) -> NodeUrl {
+        let str: &str = node.into();
+        str.parse().expect("preset node URLs are valid")
+    }
+}
+
 impl From<PresetNode> for Node {
     fn from(node: PresetNode) -> Node {
-        Self {
-            url: node.into(),
-            auth: None,
-        }
+        let url: NodeUrl = node.into();
+        url.into()
     }
 }
 
 impl From<PresetNode> for Vec<Node> {
     fn from(node: PresetNode) -> Vec<Node> {
-        let urls: Vec<String> = node.into();
-        urls.into_iter()
-            .map(|url| Node { url, auth: None })
+        let strs: Vec<&str> = node.into();
+        strs.into_iter()
+            .map(|url| url.parse().expect("preset node URLs are valid"))
             .collect()
     }
 }
@@ -0,0 +1,195 @@
+//! Reports of probing nodes.
+
+use super::{ApiVersion, Capabilities, Capability, Error, Node, NodeInfo};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::time::Duration;
+
+/// What probing a node revealed.
+#[derive(Clone, PartialEq, Debug)]
+pub struct NodeProbe {
+    pub node: Node,
+    /// The round-trip time of a `/health` request, if it succeeded.
+    pub latency: Option<Duration>,
+    /// Whether the node reported itself healthy.
+    pub healthy: bool,
+    pub api_version: Option<ApiVersion>,
+    /// How many milestones the confirmed milestone lags behind the latest one.
+    pub sync_lag: Option<u64>,
+    pub capabilities: Option<Capabilities>,
+    /// The first error met while probing.
+    pub error: Option<Error>,
+}
+
+impl NodeProbe {
+    /// A probe of `node` that has revealed nothing yet.
+    pub fn new(node: Node) -> Self {
+        Self {
+            node,
+            latency: None,
+            healthy: false,
+            api_version: None,
+            sync_lag: None,
+            capabilities: None,
+            error: None,
+        }
+    }
+
+    /// Whether the node answered everything and is healthy.
+    pub fn is_usable(&self) -> bool {
+        self.healthy && self.error.is_none()
+    }
+
+    /// Whether the node does proof of work for blocks submitted without a nonce.
+    pub fn supports_pow(&self) -> bool {
+        self.capabilities
+            .as_ref()
+            .map_or(false, |capabilities| capabilities.supports(Capability::PoW))
+    }
+
+    /// Record `info` returned by the node.
+    pub fn record_info(&mut self, info: &NodeInfo) {
+        self.healthy &= info.is_healthy();
+        self.api_version = Some(info.api_version());
+        self.sync_lag = Some(
+            info.latest_milestone_index()
+                .saturating_sub(info.confirmed_milestone_index()),
+        );
+    }
+
+    /// Record an error, keeping the first one.
+    pub fn record_error(&mut self, error: Error) {
+        self.error.get_or_insert(error);
+    }
+
+    /// Order probes from the best node to the worst one: usable nodes first, then by sync lag, then
+    /// by latency.
+    pub fn rank(&self, other: &Self) -> Ordering {
+        fn unknown_last<T: Ord>(a: Option<T>, b: Option<T>) -> Ordering {
+            match (a, b) {
+                (Some(a), Some(b)) => a.cmp(&b),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => Ordering::Equal,
+            }
+        }
+
+        other
+            .is_usable()
+            .cmp(&self.is_usable())
+            .then_with(|| unknown_last(self.sync_lag, other.sync_lag))
+            .then_with(|| unknown_last(self.latency, other.latency))
+    }
+}
+
+/// Probes of nodes, ranked from the best node to the worst one.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct ProbeReport {
+    pub probes: Vec<NodeProbe>,
+}
+
+impl ProbeReport {
+    /// Rank `probes` into a report.
+    pub fn new(mut probes: Vec<NodeProbe>) -> Self {
+        probes.sort_by(NodeProbe::rank);
+        Self { probes }
+    }
+
+    /// The best node, if any is usable.
+    pub fn best(&self) -> Option<&NodeProbe> {
+        self.probes.first().filter(|probe| probe.is_usable())
+    }
+
+    /// The usable nodes, from the best one, e.g. to construct a client with.
+    pub fn usable_nodes(&self) -> Vec<Node> {
+        self.probes
+            .iter()
+            .filter(|probe| probe.is_usable())
+            .map(|probe| probe.node.clone())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn probe(
+        name: &str,
+        healthy: bool,
+        sync_lag: Option<u64>,
+        latency_ms: Option<u64>,
+    ) -> NodeProbe {
+        NodeProbe {
+            healthy,
+            sync_lag,
+            latency: latency_ms.map(Duration::from_millis),
+            ..NodeProbe::new(
+                alloc::format!("https://{}.example.iota.org", name)
+                    .parse()
+                    .unwrap(),
+            )
+        }
+    }
+
+    fn names(report: &ProbeReport) -> Vec<&str> {
+        report
+            .probes
+            .iter()
+            .map(|probe| probe.node.url.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn ranks_usable_nodes_first_then_by_sync_lag_then_by_latency() {
+        let report = ProbeReport::new(alloc::vec![
+            probe("unhealthy", false, Some(0), Some(1)),
+            probe("slow", true, Some(0), Some(300)),
+            probe("lagging", true, Some(5), Some(10)),
+            probe("fast", true, Some(0), Some(20)),
+            probe("unknown-lag", true, None, Some(5)),
+        ]);
+
+        assert_eq!(
+            names(&report),
+            [
+                "https://fast.example.iota.org",
+                "https://slow.example.iota.org",
+                "https://lagging.example.iota.org",
+                "https://unknown-lag.example.iota.org",
+                "https://unhealthy.example.iota.org",
+            ]
+        );
+        assert_eq!(
+            report.best().map(|probe| probe.node.url.as_str()),
+            Some("https://fast.example.iota.org")
+        );
+    }
+
+    #[test]
+    fn failed_probes_are_not_usable() {
+        let mut failed = probe("failed", true, Some(0), Some(1));
+        failed.record_error(Error::HttpClientError("connection refused".into()));
+        failed.record_error(Error::Timeout);
+        let report = ProbeReport::new(alloc::vec![
+            failed,
+            probe("unknown-latency", true, Some(0), None),
+        ]);
+
+        assert_eq!(
+            names(&report),
+            [
+                "https://unknown-latency.example.iota.org",
+                "https://failed.example.iota.org",
+            ]
+        );
+        assert_eq!(
+            report.probes[1].error,
+            Some(Error::HttpClientError("connection refused".into()))
+        );
+        assert_eq!(report.usable_nodes().len(), 1);
+
+        let report = ProbeReport::new(alloc::vec![probe("unhealthy", false, None, None)]);
+        assert_eq!(report.best(), None);
+    }
+}
@@ -0,0 +1,266 @@
+//! Promotion and reattachment of blocks which are not getting referenced.
+
+use super::{BlockId, BlockMetadata, Error};
+use alloc::{boxed::Box, vec::Vec};
+use core::time::Duration;
+use typed_builder::TypedBuilder;
+
+/// How to react to a block which is not getting referenced by milestones.
+///
+/// While the block is not referenced, its metadata is checked every [`interval`](Self::interval).
+/// When the node says so, the block is promoted (a block without payload approving it is
+/// issued), or its payload is reattached in a new block with fresh parents, within the given
+/// budgets.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, TypedBuilder)]
+#[builder(field_defaults(default, setter(strip_option)))]
+pub struct PromotionPolicy {
+    /// How long to wait between checks.
+    #[builder(default = PromotionPolicy::DEFAULT_INTERVAL, setter(!strip_option))]
+    pub interval: Duration,
+    /// The maximum number of promotion blocks to issue.
+    #[builder(default = 3, setter(!strip_option))]
+    pub max_promotions: usize,
+    /// The maximum number of reattachments.
+    #[builder(default = 1, setter(!strip_option))]
+    pub max_reattachments: usize,
+    /// How long to keep watching at most. Without a timeout, the block is watched until it is
+    /// referenced or must be reattached beyond the budget.
+    pub timeout: Option<Duration>,
+}
+
+impl PromotionPolicy {
+    /// How long to wait between checks by default.
+    pub const DEFAULT_INTERVAL: Duration = Duration::from_secs(5);
+
+    /// What to do about the latest attachment, not referenced yet, described by `metadata`.
+    #[cfg(any(all(feature = "sync", feature = "std"), feature = "async"))]
+    pub(crate) fn react(&self, metadata: &BlockMetadata, report: &PromotionReport) -> Reaction {
+        if metadata.should_reattach == Some(true) {
+            if report.reattachments() < self.max_reattachments {
+                Reaction::Reattach
+            } else {
+                Reaction::GiveUp
+            }
+        } else if metadata.should_promote == Some(true) && report.promotions() < self.max_promotions
+        {
+            Reaction::Promote
+        } else {
+            Reaction::Wait
+        }
+    }
+}
+
+impl Default for PromotionPolicy {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+/// What to do about a block which is not referenced yet.
+#[cfg(any(all(feature = "sync", feature = "std"), feature = "async"))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub(crate) enum Reaction {
+    Wait,
+    Promote,
+    Reattach,
+    GiveUp,
+}
+
+/// An action taken to get a block referenced.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum PromotionAction {
+    /// A block without payload approving `block_id` was issued.
+    Promoted {
+        block_id: BlockId,
+        promotion: BlockId,
+    },
+    /// The payload of `block_id` was attached again in a new block.
+    Reattached {
+        block_id: BlockId,
+        reattachment: BlockId,
+    },
+}
+
+/// What happened while getting a block referenced.
+///
+/// ```
+/// use atoi::comm::http::DummyHttpClient;
+/// use atoi::types::{BlockId, Node, PromotionAction, PromotionPolicy};
+/// use atoi::Client;
+/// use std::sync::Arc;
+/// use std::time::Duration;
+///
+/// let client = Client::builder()
+///     .nodes(vec!["https://example.iota.org".parse::<Node>().unwrap()])
+///     .http(Arc::new(DummyHttpClient::new()))
+///     .build();
+/// let stuck: BlockId = "0x4c7a5e2d9b1f3a6c8e0d2b4f6a8c0e2d4b6f8a0c2e4d6b8f0a2c4e6d8b0f2a4c"
+///     .parse()
+///     .unwrap();
+///
+/// let policy = PromotionPolicy::builder()
+///     .interval(Duration::from_millis(10))
+///     .timeout(Duration::from_secs(1))
+///     .build();
+/// let report = client.promote_until_referenced(stuck, &policy).unwrap();
+///
+/// assert!(report.is_referenced());
+/// assert_eq!(report.reattachments(), 1);
+/// assert!(matches!(
+///     report.actions[0],
+///     PromotionAction::Reattached { block_id, .. } if block_id == stuck
+/// ));
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct PromotionReport {
+    /// Every action taken, in order.
+    pub actions: Vec<PromotionAction>,
+    /// The metadata of the attachment which got referenced, if any.
+    pub referenced: Option<BlockMetadata>,
+    /// The block, followed by its reattachments.
+    attachments: Vec<BlockId>,
+}
+
+impl PromotionReport {
+    #[cfg(any(all(feature = "sync", feature = "std"), feature = "async"))]
+    pub(crate) fn new(block_id: BlockId) -> Self {
+        Self {
+            actions: Vec::new(),
+            referenced: None,
+            attachments: alloc::vec![block_id],
+        }
+    }
+
+    /// Whether the block or one of its reattachments got referenced.
+    pub fn is_referenced(&self) -> bool {
+        self.referenced.is_some()
+    }
+
+    /// The block, followed by its reattachments. Any of them may get referenced.
+    pub fn attachments(&self) -> &[BlockId] {
+        &self.attachments
+    }
+
+    /// The latest attachment, which promotions approve.
+    pub fn latest(&self) -> BlockId {
+        self.attachments[self.attachments.len() - 1]
+    }
+
+    /// The number of promotion blocks issued.
+    pub fn promotions(&self) -> usize {
+        self.actions
+            .iter()
+            .filter(|action| matches!(action, PromotionAction::Promoted { .. }))
+            .count()
+    }
+
+    /// The number of reattachments.
+    pub fn reattachments(&self) -> usize {
+        self.attachments.len() - 1
+    }
+
+    #[cfg(any(all(feature = "sync", feature = "std"), feature = "async"))]
+    pub(crate) fn promoted(&mut self, promotion: BlockId) {
+        self.actions.push(PromotionAction::Promoted {
+            block_id: self.latest(),
+            promotion,
+        });
+    }
+
+    #[cfg(any(all(feature = "sync", feature = "std"), feature = "async"))]
+    pub(crate) fn reattached(&mut self, reattachment: BlockId) {
+        self.actions.push(PromotionAction::Reattached {
+            block_id: self.latest(),
+            reattachment,
+        });
+        self.attachments.push(reattachment);
+    }
+}
+
+/// An error which stopped getting a block referenced, with everything done until then.
+///
+/// The promotions and reattachments in [`report`](Self::report) were sent, and any of the
+/// attachments may still get referenced.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct PromotionError {
+    pub error: Error,
+    pub report: Box<PromotionReport>,
+}
+
+impl From<PromotionError> for Error {
+    fn from(promotion_error: PromotionError) -> Self {
+        promotion_error.error
+    }
+}
+
+#[cfg(all(test, any(all(feature = "sync", feature = "std"), feature = "async")))]
+mod tests {
+    use super::*;
+
+    fn metadata(should_promote: bool, should_reattach: bool) -> BlockMetadata {
+        BlockMetadata {
+            block_id: BlockId([1; 32]),
+            parents: Vec::new(),
+            is_solid: true,
+            referenced_by_milestone_index: None,
+            milestone_index: None,
+            ledger_inclusion_state: None,
+            conflict_reason: None,
+            white_flag_index: None,
+            should_promote: Some(should_promote),
+            should_reattach: Some(should_reattach),
+        }
+    }
+
+    #[test]
+    fn promotes_within_the_budget() {
+        let policy = PromotionPolicy::builder().max_promotions(2).build();
+        let mut report = PromotionReport::new(BlockId([1; 32]));
+
+        assert_eq!(
+            policy.react(&metadata(false, false), &report),
+            Reaction::Wait
+        );
+        assert_eq!(
+            policy.react(&metadata(true, false), &report),
+            Reaction::Promote
+        );
+        report.promoted(BlockId([2; 32]));
+        report.promoted(BlockId([3; 32]));
+        assert_eq!(
+            policy.react(&metadata(true, false), &report),
+            Reaction::Wait
+        );
+
+        assert_eq!(report.promotions(), 2);
+        assert_eq!(
+            report.actions[0],
+            PromotionAction::Promoted {
+                block_id: BlockId([1; 32]),
+                promotion: BlockId([2; 32]),
+            }
+        );
+    }
+
+    #[test]
+    fn reattaches_within_the_budget_then_gives_up() {
+        let policy = PromotionPolicy::builder().max_reattachments(1).build();
+        let mut report = PromotionReport::new(BlockId([1; 32]));
+
+        // Reattaching takes precedence over promoting.
+        assert_eq!(
+            policy.react(&metadata(true, true), &report),
+            Reaction::Reattach
+        );
+        report.reattached(BlockId([2; 32]));
+        assert_eq!(
+            policy.react(&metadata(true, true), &report),
+            Reaction::GiveUp
+        );
+
+        assert_eq!(report.reattachments(), 1);
+        assert_eq!(report.latest(), BlockId([2; 32]));
+        assert_eq!(report.attachments(), &[BlockId([1; 32]), BlockId([2; 32])]);
+        assert!(!report.is_referenced());
+    }
+}
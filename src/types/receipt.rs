@@ -0,0 +1,150 @@
+//! Receipts of funds migrated from the legacy network, and the treasury funding them.
+
+use super::error::Error;
+use super::{MilestoneId, Result};
+use alloc::{string::String, vec::Vec};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Receipts, with the index of the milestone including each of them.
+///
+/// ```
+/// use atoi::comm::http::DummyHttpClient;
+/// use atoi::types::Node;
+/// use atoi::Client;
+/// use std::sync::Arc;
+///
+/// let client = Client::builder()
+///     .nodes(vec!["https://example.iota.org".parse::<Node>().unwrap()])
+///     .http(Arc::new(DummyHttpClient::new()))
+///     .build();
+///
+/// let receipts = client.receipts_migrated_at(3245000).unwrap().receipts;
+/// let receipt = &receipts[0].receipt;
+/// assert!(receipt.is_final);
+/// assert_eq!(receipt.migrated_total().unwrap(), 3_000_000);
+///
+/// let treasury = client.treasury().unwrap();
+/// assert_eq!(
+///     treasury.tokens().unwrap(),
+///     receipt.transaction.output.tokens().unwrap()
+/// );
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReceiptsResponse {
+    pub receipts: Vec<IncludedReceipt>,
+}
+
+/// A receipt, with the index of the milestone including it.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IncludedReceipt {
+    pub receipt: Receipt,
+    pub milestone_index: u32,
+}
+
+/// A receipt milestone option: funds migrated from the legacy network at a legacy milestone.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Receipt {
+    #[serde(rename = "type")]
+    pub kind: u64,
+    /// The index of the legacy milestone the funds were migrated at.
+    pub migrated_at: u32,
+    /// Whether this is the last receipt of the legacy milestone.
+    #[serde(rename = "final")]
+    pub is_final: bool,
+    pub funds: Vec<MigratedFundsEntry>,
+    /// The transaction moving the migrated funds out of the treasury.
+    pub transaction: TreasuryTransactionPayload,
+}
+
+impl Receipt {
+    /// The sum of the migrated deposits.
+    pub fn migrated_total(&self) -> Result<u64> {
+        self.funds.iter().try_fold(0u64, |total, entry| {
+            total
+                .checked_add(entry.tokens()?)
+                .ok_or_else(|| Error::ResponseError("migrated funds overflow".into()))
+        })
+    }
+}
+
+/// Funds migrated from a legacy bundle to an address.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MigratedFundsEntry {
+    /// The hash of the tail transaction of the legacy bundle.
+    pub tail_transaction_hash: String,
+    /// The address receiving the funds, as raw JSON.
+    pub address: Value,
+    /// The amount of migrated base tokens, as a decimal string.
+    pub deposit: String,
+}
+
+impl MigratedFundsEntry {
+    /// The amount of migrated base tokens.
+    pub fn tokens(&self) -> Result<u64> {
+        parse_amount(&self.deposit)
+    }
+}
+
+/// A transaction spending the treasury output into a new one.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TreasuryTransactionPayload {
+    #[serde(rename = "type")]
+    pub kind: u64,
+    pub input: TreasuryInput,
+    pub output: TreasuryOutput,
+}
+
+/// The input of a treasury transaction: the milestone which created the spent treasury output.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TreasuryInput {
+    #[serde(rename = "type")]
+    pub kind: u64,
+    pub milestone_id: MilestoneId,
+}
+
+/// The output of a treasury transaction: the new treasury.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TreasuryOutput {
+    #[serde(rename = "type")]
+    pub kind: u64,
+    /// The amount of base tokens left in the treasury, as a decimal string.
+    pub amount: String,
+}
+
+impl TreasuryOutput {
+    /// The amount of base tokens left in the treasury.
+    pub fn tokens(&self) -> Result<u64> {
+        parse_amount(&self.amount)
+    }
+}
+
+/// The current treasury output.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TreasuryResponse {
+    /// The milestone which created the treasury output.
+    pub milestone_id: MilestoneId,
+    /// The amount of base tokens in the treasury, as a decimal string.
+    pub amount: String,
+}
+
+impl TreasuryResponse {
+    /// The amount of base tokens in the treasury.
+    pub fn tokens(&self) -> Result<u64> {
+        parse_amount(&self.amount)
+    }
+}
+
+fn parse_amount(amount: &str) -> Result<u64> {
+    amount
+        .parse()
+        .map_err(|_| Error::ResponseError(alloc::format!("invalid amount `{}`", amount)))
+}
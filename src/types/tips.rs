@@ -0,0 +1,154 @@
+//! Tips of the Tangle, and the selection of parents of new blocks among them.
+
+use super::error::Error;
+use super::{BlockId, Result};
+use alloc::vec::Vec;
+use core::time::Duration;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+pub struct TipsResponse {
+    pub tips: Vec<BlockId>,
+}
+
+/// The parents of a new block, selected among tips.
+///
+/// The first [`MAX`](Self::MAX) distinct tips are selected, in the order given (nodes return
+/// tips in random order), and sorted as required by the protocol. As tips quickly get approved by
+/// other blocks, parents should not be kept for long; see [`is_stale`](Self::is_stale). Parents
+/// are equal if their IDs are, whenever they were selected.
+///
+/// ```
+/// use atoi::types::{BlockId, Parents};
+///
+/// let a: BlockId = "0x0000000000000000000000000000000000000000000000000000000000000001".parse().unwrap();
+/// let b: BlockId = "0x0000000000000000000000000000000000000000000000000000000000000002".parse().unwrap();
+///
+/// let parents = Parents::new(vec![b, a, b]).unwrap();
+/// assert_eq!(parents.ids(), &[a, b]);
+/// assert!(Parents::new(vec![]).is_err());
+/// ```
+#[derive(Clone, Debug)]
+pub struct Parents {
+    ids: Vec<BlockId>,
+    #[cfg(feature = "std")]
+    selected_at: std::time::Instant,
+}
+
+impl Parents {
+    /// The minimum number of parents of a block.
+    pub const MIN: usize = 1;
+    /// The maximum number of parents of a block.
+    pub const MAX: usize = 8;
+    /// How long parents are considered fresh by default.
+    pub const MAX_AGE: Duration = Duration::from_secs(10);
+
+    /// Select parents among `tips`.
+    pub fn new<I: IntoIterator<Item = BlockId>>(tips: I) -> Result<Self> {
+        let mut ids: Vec<BlockId> = Vec::with_capacity(Self::MAX);
+
+        for tip in tips {
+            if ids.len() == Self::MAX {
+                break;
+            }
+            if !ids.contains(&tip) {
+                ids.push(tip);
+            }
+        }
+        ids.sort_unstable();
+
+        if ids.len() < Self::MIN {
+            return Err(Error::InvalidBlock("no tips to select parents from".into()));
+        }
+
+        Ok(Self {
+            ids,
+            #[cfg(feature = "std")]
+            selected_at: std::time::Instant::now(),
+        })
+    }
+
+    /// Select parents among `tips`, always including `block_id`, e.g. to promote it.
+    pub fn including<I: IntoIterator<Item = BlockId>>(block_id: BlockId, tips: I) -> Result<Self> {
+        Self::new(
+            tips.into_iter()
+                .filter(|&tip| tip != block_id)
+                .take(Self::MAX - 1)
+                .chain(core::iter::once(block_id)),
+        )
+    }
+
+    /// The IDs of the parents, sorted.
+    pub fn ids(&self) -> &[BlockId] {
+        &self.ids
+    }
+
+    /// Whether the parents were selected more than `max_age` ago. Without the `std` feature, the
+    /// age cannot be known, and parents are always stale.
+    pub fn is_stale(&self, max_age: Duration) -> bool {
+        #[cfg(feature = "std")]
+        return self.selected_at.elapsed() > max_age;
+
+        #[cfg(not(feature = "std"))]
+        return {
+            let _ = max_age;
+            true
+        };
+    }
+}
+
+impl PartialEq for Parents {
+    fn eq(&self, other: &Self) -> bool {
+        self.ids == other.ids
+    }
+}
+
+impl Eq for Parents {}
+
+impl From<Parents> for Vec<BlockId> {
+    fn from(parents: Parents) -> Vec<BlockId> {
+        parents.ids
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ids(count: u8) -> Vec<BlockId> {
+        (0..count).map(|index| BlockId([index; 32])).collect()
+    }
+
+    #[test]
+    fn selects_the_first_distinct_tips() {
+        let mut tips = ids(12);
+        tips.reverse();
+        tips.insert(1, tips[0]);
+
+        let parents = Parents::new(tips).unwrap();
+
+        assert_eq!(parents.ids(), &ids(12)[4..]);
+    }
+
+    #[test]
+    fn always_includes_the_promoted_block() {
+        let promoted = BlockId([0; 32]);
+        let mut tips = ids(12);
+        tips.reverse();
+
+        let parents = Parents::including(promoted, tips).unwrap();
+
+        assert_eq!(parents.ids().len(), Parents::MAX);
+        assert_eq!(parents.ids()[0], promoted);
+    }
+
+    #[test]
+    fn equality_ignores_the_selection_time() {
+        let parents = Parents::new(ids(3)).unwrap();
+        #[cfg(feature = "std")]
+        std::thread::sleep(Duration::from_millis(1));
+
+        assert_eq!(parents, Parents::new(ids(3)).unwrap());
+        assert_ne!(parents, Parents::new(ids(2)).unwrap());
+    }
+}
@@ -0,0 +1,164 @@
+//! The state of transactions.
+
+use super::{BlockId, BlockMetadata, ConflictReason, Error, LedgerInclusionState};
+use serde::{Deserialize, Serialize};
+
+/// The state of a transaction in the ledger, as told by the metadata of the block including it.
+///
+/// ```
+/// use atoi::comm::http::DummyHttpClient;
+/// use atoi::types::{Node, TransactionId, TransactionStatus};
+/// use atoi::Client;
+/// use std::sync::Arc;
+///
+/// let client = Client::builder()
+///     .nodes(vec!["https://example.iota.org".parse::<Node>().unwrap()])
+///     .http(Arc::new(DummyHttpClient::new()))
+///     .build();
+/// let transaction_id: TransactionId =
+///     "0x5f6e7d8c9b0a1f2e3d4c5b6a7980f1e2d3c4b5a697887766554433221100ffee"
+///         .parse()
+///         .unwrap();
+///
+/// assert!(matches!(
+///     client.transaction_status(transaction_id).unwrap(),
+///     TransactionStatus::Included { milestone_index: 470, .. }
+/// ));
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", tag = "state")]
+pub enum TransactionStatus {
+    /// The block including the transaction is not referenced by a milestone yet.
+    #[serde(rename_all = "camelCase")]
+    Pending { block_id: BlockId },
+    /// The transaction was applied to the ledger.
+    #[serde(rename_all = "camelCase")]
+    Included {
+        block_id: BlockId,
+        milestone_index: u32,
+    },
+    /// The transaction was referenced, but conflicts with the ledger and was not applied.
+    #[serde(rename_all = "camelCase")]
+    Conflicting {
+        block_id: BlockId,
+        reason: ConflictReason,
+    },
+    /// The block the node reports as including the transaction was referenced as a block
+    /// without transaction, so the node cannot tell whether the transaction was applied.
+    #[serde(rename_all = "camelCase")]
+    NoTransaction { block_id: BlockId },
+    /// The node knows no block including the transaction.
+    Unknown,
+}
+
+/// Fails with [`Error::ResponseError`] on metadata telling that the transaction was included
+/// without telling by which milestone.
+impl TryFrom<&BlockMetadata> for TransactionStatus {
+    type Error = Error;
+
+    fn try_from(metadata: &BlockMetadata) -> Result<Self, Error> {
+        let block_id = metadata.block_id;
+
+        Ok(match metadata.ledger_inclusion_state {
+            Some(LedgerInclusionState::Included) => Self::Included {
+                block_id,
+                milestone_index: metadata.referenced_by_milestone_index.ok_or_else(|| {
+                    Error::ResponseError(alloc::format!(
+                        "block {} is included without a referencing milestone",
+                        block_id
+                    ))
+                })?,
+            },
+            Some(LedgerInclusionState::Conflicting) => Self::Conflicting {
+                block_id,
+                reason: metadata.conflict_reason.unwrap_or(ConflictReason::None),
+            },
+            Some(LedgerInclusionState::NoTransaction) => Self::NoTransaction { block_id },
+            None => Self::Pending { block_id },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    const BLOCK_ID: &str = "0x9a1a0b1f81a4c25d9d6d0e8c4e6a2d8d4e0f5c2b1a3e7d6c5b4a39281706f5e4";
+
+    fn metadata(
+        referenced_by_milestone_index: Option<u32>,
+        ledger_inclusion_state: Option<LedgerInclusionState>,
+    ) -> BlockMetadata {
+        BlockMetadata {
+            block_id: BLOCK_ID.parse().unwrap(),
+            parents: Vec::new(),
+            is_solid: true,
+            referenced_by_milestone_index,
+            milestone_index: None,
+            ledger_inclusion_state,
+            conflict_reason: None,
+            white_flag_index: None,
+            should_promote: None,
+            should_reattach: None,
+        }
+    }
+
+    #[test]
+    fn from_block_metadata() {
+        let block_id: BlockId = BLOCK_ID.parse().unwrap();
+        let status = |referenced_by_milestone_index, ledger_inclusion_state| {
+            TransactionStatus::try_from(&metadata(
+                referenced_by_milestone_index,
+                ledger_inclusion_state,
+            ))
+            .unwrap()
+        };
+
+        assert_eq!(status(None, None), TransactionStatus::Pending { block_id });
+        assert_eq!(
+            status(Some(7), Some(LedgerInclusionState::Included)),
+            TransactionStatus::Included {
+                block_id,
+                milestone_index: 7
+            }
+        );
+        assert_eq!(
+            status(Some(7), Some(LedgerInclusionState::Conflicting)),
+            TransactionStatus::Conflicting {
+                block_id,
+                reason: ConflictReason::None
+            }
+        );
+        assert_eq!(
+            status(Some(7), Some(LedgerInclusionState::NoTransaction)),
+            TransactionStatus::NoTransaction { block_id }
+        );
+    }
+
+    #[test]
+    fn rejects_inclusion_without_milestone() {
+        assert!(matches!(
+            TransactionStatus::try_from(&metadata(None, Some(LedgerInclusionState::Included))),
+            Err(Error::ResponseError(reason)) if reason.contains("without a referencing milestone")
+        ));
+    }
+
+    #[test]
+    fn serializes_fields_in_camel_case() {
+        let block_id: BlockId = BLOCK_ID.parse().unwrap();
+
+        let json = serde_json::to_string(&TransactionStatus::Pending { block_id }).unwrap();
+        assert_eq!(
+            json,
+            alloc::format!(r#"{{"state":"pending","blockId":"{}"}}"#, BLOCK_ID)
+        );
+
+        let json = serde_json::to_string(&TransactionStatus::Conflicting {
+            block_id,
+            reason: ConflictReason::None,
+        })
+        .unwrap();
+        assert!(json.contains(r#""blockId""#), "{}", json);
+    }
+}
@@ -0,0 +1,152 @@
+//! Types returned by the Chrysalis REST API (v1).
+//!
+//! ```
+//! use atoi::comm::http::DummyHttpClient;
+//! use atoi::types::v1::Payload;
+//! use atoi::types::Node;
+//! use atoi::Client;
+//! use std::sync::Arc;
+//!
+//! let client = Client::builder()
+//!     .nodes(vec!["https://chrysalis.example.iota.org".parse::<Node>().unwrap()])
+//!     .http(Arc::new(DummyHttpClient::new()))
+//!     .build();
+//!
+//! let found = client.messages_by_index(b"atoi").unwrap();
+//! let message = client.message(found.message_ids[0]).unwrap();
+//!
+//! match message.payload {
+//!     Some(Payload::Indexation(indexation)) => {
+//!         assert_eq!(indexation.data_bytes().unwrap(), b"hello")
+//!     }
+//!     _ => panic!("not an indexation payload"),
+//! }
+//! ```
+
+pub use crate::types::id::MessageId;
+
+use crate::types::{Error, Result};
+use alloc::{string::String, vec::Vec};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+
+#[derive(Clone, PartialEq, PartialOrd, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InfoResponse {
+    pub name: String,
+    pub version: String,
+    pub is_healthy: bool,
+    pub network_id: String,
+    #[serde(rename = "bech32HRP")]
+    pub bech32_hrp: String,
+    #[serde(rename = "minPoWScore")]
+    pub min_pow_score: f32,
+    pub messages_per_second: f32,
+    pub referenced_messages_per_second: f32,
+    pub referenced_rate: f32,
+    pub latest_milestone_timestamp: u64,
+    pub latest_milestone_index: u64,
+    pub confirmed_milestone_index: u64,
+    pub pruning_index: u64,
+    pub features: Vec<String>,
+}
+
+/// A message, the unit of data in the Chrysalis Tangle.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Message {
+    pub network_id: String,
+    pub parent_message_ids: Vec<MessageId>,
+    pub payload: Option<Payload>,
+    pub nonce: String,
+}
+
+/// The payload of a [`Message`].
+///
+/// Only indexation payloads are typed; other payloads (transactions, milestones, receipts, ...)
+/// are kept as raw JSON.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Payload {
+    Indexation(IndexationPayload),
+    Other(Value),
+}
+
+impl Payload {
+    /// The type of indexation payloads.
+    pub const INDEXATION_TYPE: u64 = 2;
+
+    /// The type of the payload.
+    pub fn kind(&self) -> Option<u64> {
+        match self {
+            Self::Indexation(_) => Some(Self::INDEXATION_TYPE),
+            Self::Other(value) => value.get("type").and_then(Value::as_u64),
+        }
+    }
+}
+
+impl Serialize for Payload {
+    fn serialize<S: Serializer>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error> {
+        match self {
+            Self::Indexation(indexation) => serde_json::json!({
+                "type": Self::INDEXATION_TYPE,
+                "index": indexation.index,
+                "data": indexation.data,
+            })
+            .serialize(serializer),
+            Self::Other(value) => value.serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Payload {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> core::result::Result<Self, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+
+        match value.get("type").and_then(Value::as_u64) {
+            Some(Self::INDEXATION_TYPE) => IndexationPayload::deserialize(value)
+                .map(Self::Indexation)
+                .map_err(serde::de::Error::custom),
+            _ => Ok(Self::Other(value)),
+        }
+    }
+}
+
+/// A payload attaching arbitrary data to a message, findable by its index.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+pub struct IndexationPayload {
+    /// The hex-encoded index.
+    pub index: String,
+    /// The hex-encoded data.
+    #[serde(default)]
+    pub data: String,
+}
+
+impl IndexationPayload {
+    /// An indexation payload of raw `index` and `data`.
+    pub fn new(index: &[u8], data: &[u8]) -> Self {
+        Self {
+            index: hex::encode(index),
+            data: hex::encode(data),
+        }
+    }
+
+    /// The decoded index.
+    pub fn index_bytes(&self) -> Result<Vec<u8>> {
+        hex::decode(&self.index).map_err(|err| Error::ResponseError(alloc::format!("{}", err)))
+    }
+
+    /// The decoded data.
+    pub fn data_bytes(&self) -> Result<Vec<u8>> {
+        hex::decode(&self.data).map_err(|err| Error::ResponseError(alloc::format!("{}", err)))
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MessagesByIndexResponse {
+    /// The hex-encoded index.
+    pub index: String,
+    pub max_results: u64,
+    pub count: u64,
+    pub message_ids: Vec<MessageId>,
+}
@@ -0,0 +1,139 @@
+//! Generations of the node APIs.
+
+use super::{v1, Capability, InfoResponse};
+use alloc::string::String;
+use serde::{Deserialize, Serialize};
+
+/// A generation of the node APIs, served under `/api/v1` or `/api/v2`.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ApiVersion {
+    /// The Chrysalis APIs.
+    V1,
+    /// The Stardust APIs.
+    V2,
+}
+
+impl Default for ApiVersion {
+    fn default() -> Self {
+        Self::V2
+    }
+}
+
+impl ApiVersion {
+    /// The route of the core APIs of this generation, as listed in `/api/routes`.
+    pub fn route(self) -> &'static str {
+        match self {
+            Self::V1 => "core/v1",
+            Self::V2 => "core/v2",
+        }
+    }
+}
+
+impl From<ApiVersion> for Capability {
+    fn from(version: ApiVersion) -> Self {
+        Self::Core(version)
+    }
+}
+
+/// General information about a node, of either API generation.
+///
+/// [`Client::info`](crate::Client::info) picks the generation spoken by the preferred node:
+///
+/// ```
+/// use atoi::comm::http::DummyHttpClient;
+/// use atoi::types::{ApiVersion, Node};
+/// use atoi::Client;
+/// use std::sync::Arc;
+///
+/// for (url, version) in [
+///     ("https://example.iota.org", ApiVersion::V2),
+///     ("https://chrysalis.example.iota.org", ApiVersion::V1),
+/// ] {
+///     let client = Client::builder()
+///         .nodes(vec![url.parse::<Node>().unwrap()])
+///         .http(Arc::new(DummyHttpClient::new()))
+///         .build();
+///     let info = client.info().unwrap();
+///
+///     assert_eq!(info.api_version(), version);
+///     assert!(info.is_healthy());
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+#[allow(clippy::large_enum_variant)]
+pub enum NodeInfo {
+    Chrysalis(v1::InfoResponse),
+    Stardust(InfoResponse),
+}
+
+impl NodeInfo {
+    pub fn api_version(&self) -> ApiVersion {
+        match self {
+            Self::Chrysalis(_) => ApiVersion::V1,
+            Self::Stardust(_) => ApiVersion::V2,
+        }
+    }
+
+    /// The name of the node software.
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Chrysalis(info) => &info.name,
+            Self::Stardust(info) => &info.name,
+        }
+    }
+
+    /// The version of the node software.
+    pub fn version(&self) -> &str {
+        match self {
+            Self::Chrysalis(info) => &info.version,
+            Self::Stardust(info) => &info.version,
+        }
+    }
+
+    pub fn is_healthy(&self) -> bool {
+        match self {
+            Self::Chrysalis(info) => info.is_healthy,
+            Self::Stardust(info) => info.status.is_healthy,
+        }
+    }
+
+    /// The name of the network of the node (its network ID on Chrysalis nodes).
+    pub fn network_name(&self) -> &str {
+        match self {
+            Self::Chrysalis(info) => &info.network_id,
+            Self::Stardust(info) => &info.protocol.network_name,
+        }
+    }
+
+    /// The human-readable part of Bech32 addresses on the network of the node.
+    pub fn bech32_hrp(&self) -> &str {
+        match self {
+            Self::Chrysalis(info) => &info.bech32_hrp,
+            Self::Stardust(info) => &info.protocol.bech32_hrp,
+        }
+    }
+
+    pub fn latest_milestone_index(&self) -> u64 {
+        match self {
+            Self::Chrysalis(info) => info.latest_milestone_index,
+            Self::Stardust(info) => info.status.latest_milestone.index,
+        }
+    }
+
+    pub fn confirmed_milestone_index(&self) -> u64 {
+        match self {
+            Self::Chrysalis(info) => info.confirmed_milestone_index,
+            Self::Stardust(info) => info.status.confirmed_milestone.index,
+        }
+    }
+
+    /// The optional features enabled on the node, e.g. `PoW`.
+    pub fn features(&self) -> &[String] {
+        match self {
+            Self::Chrysalis(info) => &info.features,
+            Self::Stardust(info) => &info.features,
+        }
+    }
+}